@@ -0,0 +1,170 @@
+//! Asynchronous validation.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+use validator::{Validate, ValidationErrors};
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Validation which needs I/O, like checking that a username is not taken yet.
+///
+/// [`AsyncValidated`](struct.AsyncValidated.html) extractor runs it after
+/// synchronous validation with `Validate` succeeds, so the checks can rely
+/// on the value being well-formed. Application state is available through
+/// the request's `app_data`.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::HttpRequest;
+/// use actix_web_validator::AsyncValidate;
+/// use futures::future::LocalBoxFuture;
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationError, ValidationErrors};
+///
+/// struct Users(Vec<String>);
+///
+/// impl Users {
+///     async fn exists(&self, username: &str) -> bool {
+///         self.0.iter().any(|user| user == username)
+///     }
+/// }
+///
+/// #[derive(Deserialize, Validate)]
+/// struct SignUp {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// impl AsyncValidate for SignUp {
+///     fn validate_async<'a>(
+///         &'a self,
+///         req: &'a HttpRequest,
+///     ) -> LocalBoxFuture<'a, Result<(), ValidationErrors>> {
+///         Box::pin(async move {
+///             let users = req.app_data::<Users>().unwrap();
+///             if users.exists(&self.username).await {
+///                 let mut errors = ValidationErrors::new();
+///                 errors.add("username", ValidationError::new("taken"));
+///                 return Err(errors);
+///             }
+///             Ok(())
+///         })
+///     }
+/// }
+/// ```
+pub trait AsyncValidate {
+    /// Validate the value with access to the request.
+    fn validate_async<'a>(
+        &'a self,
+        req: &'a HttpRequest,
+    ) -> LocalBoxFuture<'a, Result<(), ValidationErrors>>;
+}
+
+/// Extract typed information with any of the crate's extractors and validate
+/// it with `Validate` and then [`AsyncValidate`](trait.AsyncValidate.html).
+///
+/// Errors of both steps are reported the same way, through the inner
+/// extractor's configuration, e.g. [**JsonConfig**](struct.JsonConfig.html)
+/// for `AsyncValidated<Json<T>>`.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App, HttpRequest};
+/// use actix_web_validator::{AsyncValidate, AsyncValidated, Json};
+/// use futures::future::LocalBoxFuture;
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationError, ValidationErrors};
+///
+/// #[derive(Deserialize, Validate)]
+/// struct SignUp {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// impl AsyncValidate for SignUp {
+///     fn validate_async<'a>(
+///         &'a self,
+///         _req: &'a HttpRequest,
+///     ) -> LocalBoxFuture<'a, Result<(), ValidationErrors>> {
+///         Box::pin(async move {
+///             // query the database here
+///             Ok(())
+///         })
+///     }
+/// }
+///
+/// async fn index(sign_up: AsyncValidated<Json<SignUp>>) -> String {
+///     format!("Welcome {}!", sign_up.username)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/sign-up").route(web::post().to(index)));
+/// }
+/// ```
+pub struct AsyncValidated<E: Extractor>(pub E::Inner);
+
+impl<E: Extractor> AsyncValidated<E> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> E::Inner {
+        self.0
+    }
+}
+
+impl<E: Extractor> AsRef<E::Inner> for AsyncValidated<E> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E: Extractor> Deref for AsyncValidated<E> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E> fmt::Debug for AsyncValidated<E>
+where
+    E: Extractor,
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E> FromRequest for AsyncValidated<E>
+where
+    E: Extractor,
+    E::Future: 'static,
+    E::Inner: Validate + AsyncValidate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let extract = E::extract(&req, payload);
+        async move {
+            let result = async {
+                let value = extract.await?;
+                value.validate()?;
+                value.validate_async(&req).await?;
+                Ok::<_, Error>(value)
+            }
+            .await;
+            result.map(AsyncValidated).map_err(|e| E::error(e, &req))
+        }
+        .boxed_local()
+    }
+}
@@ -0,0 +1,192 @@
+//! Batch extractor.
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{FromRequest, HttpRequest, HttpResponse};
+use futures::future::{FutureExt, LocalBoxFuture};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+use crate::error::_flatten_errors;
+use crate::extractor::Extractor;
+use crate::json::Json;
+
+/// Batch extractor. Allow to extract a JSON array from request's payload
+/// and validate each of its elements separately.
+///
+/// Unlike `Json<Vec<T>>`, a single invalid element does not reject the whole
+/// request. The handler receives valid elements together with their indexes
+/// and validation errors of the rest. Payload deserialization errors still
+/// reject the request.
+///
+/// [**JsonConfig**](struct.JsonConfig.html) allows to configure extraction
+/// process.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App, HttpResponse};
+/// use actix_web_validator::Batch;
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Item {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// /// deserialize array of `Item` from request's body
+/// async fn index(items: Batch<Item>) -> HttpResponse {
+///     for (index, item) in items.iter() {
+///         println!("Item #{} is valid: {}", index, item.email);
+///     }
+///     items.multi_status_response()
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///        web::resource("/items:batch").route(
+///            web::post().to(index))
+///     );
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Batch<T> {
+    items: Vec<(usize, T)>,
+    errors: BTreeMap<usize, ValidationErrors>,
+}
+
+impl<T> Batch<T> {
+    /// Valid elements with their indexes in the original array.
+    pub fn items(&self) -> &[(usize, T)] {
+        &self.items
+    }
+
+    /// Validation errors of rejected elements by their indexes in the original array.
+    pub fn errors(&self) -> &BTreeMap<usize, ValidationErrors> {
+        &self.errors
+    }
+
+    /// Returns `true` if every element of the batch is valid.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Total number of elements in the original array.
+    pub fn total(&self) -> usize {
+        self.items.len() + self.errors.len()
+    }
+
+    /// Iterate over valid elements and their indexes.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.items.iter().map(|(index, item)| (*index, item))
+    }
+
+    /// Deconstruct to valid elements and validation errors.
+    pub fn into_parts(self) -> (Vec<(usize, T)>, BTreeMap<usize, ValidationErrors>) {
+        (self.items, self.errors)
+    }
+
+    /// Collect errors of all rejected elements into a single `ValidationErrors`.
+    ///
+    /// Elements are stored as a list at the root, so
+    /// [`flatten_errors`](error/fn.flatten_errors.html) reports them by paths like `[3].email`.
+    pub fn validation_errors(&self) -> Option<ValidationErrors> {
+        if self.errors.is_empty() {
+            return None;
+        }
+        let list = self
+            .errors
+            .iter()
+            .map(|(index, errors)| (*index, Box::new(errors.clone())))
+            .collect();
+        let mut errors = ValidationErrors::new();
+        errors
+            .errors_mut()
+            .insert(Cow::Borrowed(""), ValidationErrorsKind::List(list));
+        Some(errors)
+    }
+
+    /// Build `207 Multi-Status` response with status of each element.
+    ///
+    /// The body is a JSON array in the order of the original elements, e.g.
+    /// `[{"index":0,"status":200},{"index":1,"status":400,"errors":[{"field":"[1].email","code":"email","message":null}]}]`.
+    pub fn multi_status_response(&self) -> HttpResponse {
+        let mut statuses = self
+            .items
+            .iter()
+            .map(|(index, _)| (*index, json!({ "index": index, "status": 200 })))
+            .collect::<Vec<_>>();
+        statuses.extend(self.errors.iter().map(|(index, errors)| {
+            let errors = _flatten_errors(errors, Some(format!("[{index}]")), Some(1))
+                .into_iter()
+                .map(|(_, field, error)| {
+                    json!({ "field": field, "code": error.code, "message": error.message })
+                })
+                .collect::<Vec<_>>();
+            (
+                *index,
+                json!({ "index": index, "status": 400, "errors": errors }),
+            )
+        }));
+        statuses.sort_by_key(|(index, _)| *index);
+        HttpResponse::build(StatusCode::MULTI_STATUS).json(
+            statuses
+                .into_iter()
+                .map(|(_, status)| status)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<T> Deref for Batch<T> {
+    type Target = [(usize, T)];
+
+    fn deref(&self) -> &[(usize, T)] {
+        &self.items
+    }
+}
+
+impl<T> From<Vec<T>> for Batch<T>
+where
+    T: Validate,
+{
+    /// Validate each element of the vector separately.
+    fn from(data: Vec<T>) -> Self {
+        let mut items = Vec::with_capacity(data.len());
+        let mut errors = BTreeMap::new();
+        for (index, item) in data.into_iter().enumerate() {
+            match item.validate() {
+                Ok(_) => items.push((index, item)),
+                Err(e) => {
+                    errors.insert(index, e);
+                }
+            }
+        }
+        Batch { items, errors }
+    }
+}
+
+impl<T> FromRequest for Batch<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        <Json<Vec<T>> as Extractor>::extract(&req, payload)
+            .map(move |res| match res {
+                Ok(data) => Ok(Batch::from(data)),
+                Err(e) => Err(<Json<Vec<T>> as Extractor>::error(e, &req)),
+            })
+            .boxed_local()
+    }
+}
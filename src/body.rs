@@ -0,0 +1,77 @@
+//! Raw payload reading.
+use actix_web::dev::Payload;
+use actix_web::error::PayloadError;
+use actix_web::http::header::{CONTENT_ENCODING, CONTENT_LENGTH, TRANSFER_ENCODING};
+use actix_web::http::Version;
+use actix_web::web::{Bytes, BytesMut};
+use actix_web::{FromRequest, HttpRequest};
+use futures::StreamExt;
+
+/// Value of the request's `Content-Length` header.
+fn content_length(req: &HttpRequest) -> Option<usize> {
+    req.headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|l| l.to_str().ok())
+        .and_then(|s| s.parse::<usize>().ok())
+}
+
+/// Whether the request has no payload at all or an empty one.
+///
+/// HTTP/1 request without both `Content-Length` and `Transfer-Encoding`
+/// headers has no body.
+pub(crate) fn is_empty(req: &HttpRequest, payload: &Payload) -> bool {
+    if matches!(payload, Payload::None) {
+        return true;
+    }
+    match content_length(req) {
+        Some(length) => length == 0,
+        None => req.version() < Version::HTTP_2 && !req.headers().contains_key(TRANSFER_ENCODING),
+    }
+}
+
+/// Whether the request's payload has `Content-Encoding` other than identity.
+fn is_encoded(req: &HttpRequest) -> bool {
+    req.headers()
+        .get(CONTENT_ENCODING)
+        .is_some_and(|encoding| !encoding.as_bytes().eq_ignore_ascii_case(b"identity"))
+}
+
+/// Read the whole request's payload, failing with `PayloadError::Overflow`
+/// as soon as it exceeds `limit` bytes.
+///
+/// Encoded payload is decoded by actix-web the same way as for its own `Json`
+/// and `Form` extractors, so it is subject to `PayloadConfig` limit as well.
+pub(crate) async fn read_body(
+    req: HttpRequest,
+    mut payload: Payload,
+    limit: usize,
+) -> Result<Bytes, PayloadError> {
+    if is_encoded(&req) {
+        let body = Bytes::from_request(&req, &mut payload)
+            .await
+            .map_err(|e| match e.as_error::<PayloadError>() {
+                Some(PayloadError::Overflow) => PayloadError::Overflow,
+                Some(PayloadError::UnknownLength) => PayloadError::UnknownLength,
+                _ => PayloadError::EncodingCorrupted,
+            })?;
+        if body.len() > limit {
+            return Err(PayloadError::Overflow);
+        }
+        return Ok(body);
+    }
+
+    let length = content_length(&req);
+    if length.is_some_and(|length| length > limit) {
+        return Err(PayloadError::Overflow);
+    }
+
+    let mut body = BytesMut::with_capacity(length.unwrap_or(0));
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > limit {
+            return Err(PayloadError::Overflow);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.freeze())
+}
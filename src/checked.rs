@@ -0,0 +1,132 @@
+//! Extractor delivering validation errors to the handler.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::{Validate, ValidationErrors};
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Extract typed information with any of the crate's extractors and validate
+/// it, but let the handler decide what to do with validation errors, e.g. to
+/// save an invalid draft and still report what is wrong with it.
+///
+/// Only deserialization errors reject the request.
+/// [`rejection`](#method.rejection) builds the error the inner extractor
+/// would respond with, using its configuration.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App, HttpResponse};
+/// use actix_web_validator::{Checked, Json};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Draft {
+///     #[validate(length(min = 1))]
+///     title: String,
+/// }
+///
+/// async fn save_draft(draft: Checked<Json<Draft>>) -> Result<HttpResponse, actix_web::Error> {
+///     // store the draft...
+///     match draft.rejection() {
+///         Some(error) => Err(error),
+///         None => Ok(HttpResponse::Ok().finish()),
+///     }
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/drafts").route(web::post().to(save_draft)));
+/// }
+/// ```
+pub struct Checked<E: Extractor> {
+    value: E::Inner,
+    errors: Option<ValidationErrors>,
+    req: HttpRequest,
+}
+
+impl<E: Extractor> Checked<E> {
+    /// Validation errors of the value, if any.
+    pub fn errors(&self) -> Option<&ValidationErrors> {
+        self.errors.as_ref()
+    }
+
+    /// Whether the value passed validation.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_none()
+    }
+
+    /// Error the inner extractor would reject the request with, if the value
+    /// is invalid.
+    pub fn rejection(&self) -> Option<actix_web::Error> {
+        self.errors
+            .clone()
+            .map(|errors| E::error(Error::from(errors), &self.req))
+    }
+
+    /// Deconstruct to the value and its validation errors.
+    pub fn into_parts(self) -> (E::Inner, Option<ValidationErrors>) {
+        (self.value, self.errors)
+    }
+
+    /// Deconstruct to the value, whether it is valid or not.
+    pub fn into_inner(self) -> E::Inner {
+        self.value
+    }
+}
+
+impl<E: Extractor> AsRef<E::Inner> for Checked<E> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.value
+    }
+}
+
+impl<E: Extractor> Deref for Checked<E> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.value
+    }
+}
+
+impl<E: Extractor> fmt::Debug for Checked<E>
+where
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checked")
+            .field("value", &self.value)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl<E> FromRequest for Checked<E>
+where
+    E: Extractor + 'static,
+    E::Inner: Validate,
+    E::Future: 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| match res {
+                Ok(value) => Ok(Checked {
+                    errors: value.validate().err(),
+                    value,
+                    req,
+                }),
+                Err(e) => Err(E::error(e, &req)),
+            })
+            .boxed_local()
+    }
+}
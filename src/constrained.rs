@@ -0,0 +1,235 @@
+//! Newtypes with constraints declared inline.
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+use validator::{
+    Validate, ValidateLength, ValidationError, ValidationErrors, ValidationErrorsKind,
+};
+
+/// Integer bounded by `MIN` and `MAX` (both inclusive).
+///
+/// Can be used directly in extractors, e.g. `Path<Range<u32, 1, 100>>`, or as
+/// a `#[validate(nested)]` field. Errors have the `range` code and are
+/// reported for the field itself, or for the empty field on top level.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{Path, Range};
+///
+/// async fn index(id: Path<Range<u32, 1, 100>>) -> String {
+///     format!("Item {}", **id)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/items/{id}").route(web::get().to(index)));
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Range<T, const MIN: i64, const MAX: i64>(pub T);
+
+/// Value which length is bounded by `MIN` and `MAX` (both inclusive),
+/// e.g. `String` or `Vec<T>`.
+///
+/// Errors have the `length` code and are reported like the ones of
+/// [`Range`](struct.Range.html).
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{Json, Len};
+///
+/// async fn index(tags: Json<Len<Vec<String>, 1, 10>>) -> String {
+///     tags.join(", ")
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/tags").route(web::post().to(index)));
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Len<T, const MIN: u64, const MAX: u64>(pub T);
+
+/// Tuple of validated values, e.g. several path segments.
+///
+/// `Validate` can't be implemented for tuples outside of the `validator`
+/// crate, so `Path<(u32, String)>` needs this wrapper to be validated.
+/// Errors of each element are reported under its index.
+/// Tuples of up to 6 elements are supported.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{Len, Path, Range, Tuple};
+///
+/// async fn index(path: Path<Tuple<(Range<u32, 1, 100>, Len<String, 3, 32>)>>) -> String {
+///     let (id, name) = &path.0;
+///     format!("Item {} of {}", **id, **name)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/items/{id}/{name}").route(web::get().to(index)));
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Tuple<T>(pub T);
+
+macro_rules! bounded_impls {
+    ($name:ident, $bound:ty) => {
+        impl<T, const MIN: $bound, const MAX: $bound> $name<T, MIN, MAX> {
+            /// Deconstruct to an inner value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T, const MIN: $bound, const MAX: $bound> AsRef<T> for $name<T, MIN, MAX> {
+            fn as_ref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T, const MIN: $bound, const MAX: $bound> Deref for $name<T, MIN, MAX> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T: fmt::Debug, const MIN: $bound, const MAX: $bound> fmt::Debug
+            for $name<T, MIN, MAX>
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+bounded_impls!(Range, i64);
+bounded_impls!(Len, u64);
+
+/// Key of the errors which `#[validate(nested)]` attaches to the field
+/// itself rather than nesting them, as it does for collections.
+pub(crate) const FIELD: &str = "_tmp_validator";
+
+/// Errors of a value reported for the field holding it.
+pub(crate) fn field_errors(error: ValidationError) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    errors.add(FIELD, error);
+    errors
+}
+
+fn bounds_error(code: &'static str, min: impl Serialize, max: impl Serialize) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.add_param(Cow::from("min"), &min);
+    error.add_param(Cow::from("max"), &max);
+    error
+}
+
+impl<T, const MIN: i64, const MAX: i64> Validate for Range<T, MIN, MAX>
+where
+    T: Copy + Into<i128> + Serialize,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let value: i128 = self.0.into();
+        if (MIN as i128..=MAX as i128).contains(&value) {
+            return Ok(());
+        }
+        let mut error = bounds_error("range", MIN, MAX);
+        error.add_param(Cow::from("value"), &self.0);
+        Err(field_errors(error))
+    }
+}
+
+impl<T, const MIN: u64, const MAX: u64> Validate for Len<T, MIN, MAX>
+where
+    T: ValidateLength<u64> + Serialize,
+{
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if self.0.validate_length(Some(MIN), Some(MAX), None) {
+            return Ok(());
+        }
+        let mut error = bounds_error("length", MIN, MAX);
+        error.add_param(Cow::from("value"), &self.0);
+        Err(field_errors(error))
+    }
+}
+
+impl<T> Tuple<T> {
+    /// Deconstruct to an inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Tuple<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for Tuple<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tuple<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Put errors of the tuple element under its index, the way
+/// `#[validate(nested)]` does for struct fields.
+fn add_element_errors(errors: &mut ValidationErrors, index: usize, mut element: ValidationErrors) {
+    let kind = match element.errors_mut().remove(FIELD) {
+        Some(kind) => kind,
+        None => ValidationErrorsKind::Struct(Box::new(element)),
+    };
+    errors
+        .errors_mut()
+        .insert(Cow::from(index.to_string()), kind);
+}
+
+macro_rules! tuple_impls {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: Validate),+> Validate for Tuple<($($name,)+)> {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                $(
+                    if let Err(e) = self.0.$index.validate() {
+                        add_element_errors(&mut errors, $index, e);
+                    }
+                )+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        }
+    };
+}
+
+tuple_impls!(A 0);
+tuple_impls!(A 0, B 1);
+tuple_impls!(A 0, B 1, C 2);
+tuple_impls!(A 0, B 1, C 2, D 3);
+tuple_impls!(A 0, B 1, C 2, D 3, E 4);
+tuple_impls!(A 0, B 1, C 2, D 3, E 4, F 5);
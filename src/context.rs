@@ -0,0 +1,150 @@
+//! Extractor validating with context.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{web, FromRequest, HttpMessage, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::{ValidateArgs, ValidationErrors};
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Extract typed information with any of the crate's extractors and validate it
+/// with a context, using `ValidateArgs` trait from *validator* crate.
+///
+/// The value's type must be validated with `#[validate(context = ...)]`.
+/// The context is looked up in `app_data` (either as is or wrapped in
+/// `web::Data`) and then in request extensions, so it can be set by
+/// a middleware. Missing context is a server misconfiguration and
+/// results in `500 Internal Server Error`.
+///
+/// Deserialization is configured with the inner extractor's configuration,
+/// e.g. [**JsonConfig**](struct.JsonConfig.html) for `WithContext<Json<T>>`.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{Json, WithContext};
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationError};
+///
+/// struct AllowedCurrencies(Vec<&'static str>);
+///
+/// fn validate_currency(currency: &str, allowed: &AllowedCurrencies) -> Result<(), ValidationError> {
+///     if !allowed.0.contains(&currency) {
+///         return Err(ValidationError::new("currency"));
+///     }
+///     Ok(())
+/// }
+///
+/// #[derive(Deserialize, Validate)]
+/// #[validate(context = AllowedCurrencies)]
+/// struct Payment {
+///     #[validate(custom(function = "validate_currency", use_context))]
+///     currency: String,
+///     amount: u64,
+/// }
+///
+/// /// deserialize `Payment` from request's body and validate it with `AllowedCurrencies`
+/// async fn index(payment: WithContext<Json<Payment>>) -> String {
+///     format!("Paid {} {}", payment.amount, payment.currency)
+/// }
+///
+/// fn main() {
+///     let app = App::new()
+///         .app_data(AllowedCurrencies(vec!["EUR", "USD"]))
+///         .service(web::resource("/pay").route(web::post().to(index)));
+/// }
+/// ```
+pub struct WithContext<E: Extractor>(pub E::Inner);
+
+impl<E: Extractor> WithContext<E> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> E::Inner {
+        self.0
+    }
+}
+
+impl<E: Extractor> AsRef<E::Inner> for WithContext<E> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E: Extractor> Deref for WithContext<E> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E> fmt::Debug for WithContext<E>
+where
+    E: Extractor,
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E, C> FromRequest for WithContext<E>
+where
+    E: Extractor,
+    E::Future: 'static,
+    E::Inner: for<'a> ValidateArgs<'a, Args = &'a C>,
+    C: 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| {
+                let value = res.map_err(|e| E::error(e, &req))?;
+                match validate_with_context(&value, &req) {
+                    Some(Ok(())) => Ok(WithContext(value)),
+                    Some(Err(e)) => Err(E::error(Error::from(e), &req)),
+                    None => {
+                        log::error!(
+                            "Validation context `{}` is not configured. \
+                             Request path: {}",
+                            std::any::type_name::<C>(),
+                            req.path()
+                        );
+                        Err(actix_web::error::ErrorInternalServerError(
+                            "Validation context is not configured",
+                        ))
+                    }
+                }
+            })
+            .boxed_local()
+    }
+}
+
+/// Validate value with the context from `app_data` or request extensions.
+///
+/// Returns `None` if the context is not found.
+pub(crate) fn validate_with_context<T, C>(
+    value: &T,
+    req: &HttpRequest,
+) -> Option<Result<(), ValidationErrors>>
+where
+    T: for<'a> ValidateArgs<'a, Args = &'a C>,
+    C: 'static,
+{
+    if let Some(context) = req.app_data::<C>() {
+        return Some(value.validate_with_args(context));
+    }
+    if let Some(context) = req.app_data::<web::Data<C>>() {
+        return Some(value.validate_with_args(context.get_ref()));
+    }
+    req.extensions()
+        .get::<C>()
+        .map(|context| value.validate_with_args(context))
+}
@@ -0,0 +1,288 @@
+//! Csv extractor.
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+use serde::de::DeserializeOwned;
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+use crate::body::read_body;
+use crate::de::{locate, Value};
+use crate::error::Error;
+
+/// Csv can be used for extracting typed rows and validation
+/// from request's `text/csv` payload.
+///
+/// Headers of the document are mapped to fields of the type `T`, which must
+/// implement the `Deserialize` trait from *serde* and `Validate` trait
+/// from *validator* crate. Each row is validated separately and validation
+/// errors are reported by the index of the row, not counting the header,
+/// e.g. `[2].email` for the third row.
+///
+/// This extractor is available with `csv` feature.
+///
+/// [**CsvConfig**](struct.CsvConfig.html) allows to configure extraction
+/// process.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::Csv;
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Contact {
+///     #[validate(email)]
+///     email: String,
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// /// deserialize rows of `Contact` from request's body
+/// async fn index(contacts: Csv<Contact>) -> String {
+///     format!("Imported {} contacts", contacts.len())
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///        web::resource("/contacts/import").route(
+///            web::post().to(index))
+///     );
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Csv<T>(pub Vec<T>);
+
+impl<T> Csv<T> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> AsRef<Vec<T>> for Csv<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> Deref for Csv<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> FromRequest for Csv<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req2 = req.clone();
+        let config = req.app_data::<CsvConfig>().cloned().unwrap_or_default();
+        let error_handler = config.ehandler.clone();
+        let payload = payload.take();
+        let req = req.clone();
+
+        async move {
+            let mime = req2.mime_type().map_err(|_| Error::ContentType)?;
+            if !mime.is_some_and(|mime| {
+                mime.essence_str() == mime::TEXT_CSV.essence_str()
+                    || config.content_type.as_ref().is_some_and(|f| f(mime))
+            }) {
+                return Err(Error::ContentType);
+            }
+            let body = read_body(req2, payload, config.limit).await?;
+            config.parse(&body).map(Csv)
+        }
+        .map(move |res| match res {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                log::debug!(
+                    "Failed to deserialize Csv from payload. \
+                     Request path: {}",
+                    req.path()
+                );
+                if let Some(error_handler) = error_handler {
+                    Err((error_handler)(e, &req))
+                } else {
+                    Err(e.into())
+                }
+            }
+        })
+        .boxed_local()
+    }
+}
+
+type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
+
+/// Csv extractor configuration
+///
+/// ```rust
+/// use actix_web::{error, web, App, FromRequest, HttpResponse};
+/// use serde::Deserialize;
+/// use actix_web_validator::{Csv, CsvConfig};
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Contact {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// /// deserialize at most 1000 rows from request's body, max payload size is 1Mb
+/// async fn index(contacts: Csv<Contact>) -> String {
+///     format!("Imported {} contacts", contacts.len())
+/// }
+///
+/// fn main() {
+///     let csv_config = CsvConfig::default()
+///         .limit(1_048_576)
+///         .max_rows(1000)
+///         .delimiter(b';')
+///         .collect_errors(true)  // <- report all invalid rows at once
+///         .error_handler(|err, req| {  // <- create custom error response
+///             error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+///         });
+///     let app = App::new().service(
+///         web::resource("/contacts/import")
+///             .app_data(csv_config)
+///             .route(web::post().to(index))
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CsvConfig {
+    limit: usize,
+    max_rows: Option<usize>,
+    delimiter: u8,
+    collect_errors: bool,
+    ehandler: Option<ErrHandler>,
+    content_type: Option<Arc<dyn Fn(mime::Mime) -> bool + Send + Sync>>,
+}
+
+impl CsvConfig {
+    /// Change max size of payload. By default max size is 256Kb
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Change max number of rows, not counting the header, failing with
+    /// `Error::TooManyRows` above it. By default number of rows is not limited
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Change field delimiter. By default it is `,`
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Validate all rows and report errors of every invalid row instead of
+    /// failing on the first one. Disabled by default
+    pub fn collect_errors(mut self, collect_errors: bool) -> Self {
+        self.collect_errors = collect_errors;
+        self
+    }
+
+    /// Set custom error handler
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        self.ehandler = Some(Arc::new(f));
+        self
+    }
+
+    /// Set predicate for allowed content types besides `text/csv`
+    pub fn content_type<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(mime::Mime) -> bool + Send + Sync + 'static,
+    {
+        self.content_type = Some(Arc::new(predicate));
+        self
+    }
+
+    fn parse<T>(&self, body: &[u8]) -> Result<Vec<T>, Error>
+    where
+        T: DeserializeOwned + Validate,
+    {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .from_reader(body);
+        let headers = reader.headers()?.clone();
+
+        let mut rows = Vec::new();
+        let mut errors = BTreeMap::new();
+        for (index, record) in reader.records().enumerate() {
+            if let Some(max_rows) = self.max_rows.filter(|max_rows| index >= *max_rows) {
+                return Err(Error::TooManyRows(max_rows));
+            }
+            let record = record?;
+            let row = match record.deserialize::<T>(Some(&headers)) {
+                Ok(row) => row.validate().map(|_| row),
+                Err(e) => {
+                    let pairs = || {
+                        let columns = headers.iter().zip(record.iter());
+                        Some(Value::Map(
+                            columns
+                                .map(|(key, value)| (key.to_owned(), Value::Str(value.to_owned())))
+                                .collect(),
+                        ))
+                    };
+                    match locate::<T, _>(e.into(), false, pairs) {
+                        Error::Validate(errors) => Err(errors),
+                        e => return Err(e),
+                    }
+                }
+            };
+            match row {
+                Ok(row) => rows.push(row),
+                Err(e) => {
+                    errors.insert(index, Box::new(e));
+                    if !self.collect_errors {
+                        break;
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(rows)
+        } else {
+            let mut validation_errors = ValidationErrors::new();
+            validation_errors
+                .errors_mut()
+                .insert(Cow::Borrowed(""), ValidationErrorsKind::List(errors));
+            Err(Error::from(validation_errors))
+        }
+    }
+}
+
+impl Default for CsvConfig {
+    fn default() -> Self {
+        Self {
+            limit: 262_144,
+            max_rows: None,
+            delimiter: b',',
+            collect_errors: false,
+            ehandler: None,
+            content_type: None,
+        }
+    }
+}
@@ -0,0 +1,678 @@
+//! Deserializers for values collected from several parts of the request.
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, DeserializeSeed, Error as _, IntoDeserializer, Visitor};
+use validator::ValidationErrors;
+
+use crate::scalar::rejection;
+use crate::unknown_fields::{insert_error, Segment};
+
+/// Bytes of [`Value::Marker`] with `index`, which are not valid UTF-8.
+fn marker(index: usize) -> [u8; 9] {
+    let mut bytes = [0xff; 9];
+    bytes[1..].copy_from_slice(&(index as u64).to_le_bytes());
+    bytes
+}
+
+/// Index of the marker which was rejected as `unexp`.
+fn marker_index(unexp: &de::Unexpected<'_>) -> Option<usize> {
+    match unexp {
+        de::Unexpected::Bytes([0xff, rest @ ..]) => {
+            Some(u64::from_le_bytes((*rest).try_into().ok()?) as usize)
+        }
+        _ => None,
+    }
+}
+
+/// Error of [`ValueDeserializer`].
+#[derive(Debug)]
+pub(crate) struct Error {
+    message: String,
+    path: Vec<Segment>,
+    marker: Option<usize>,
+}
+
+impl Error {
+    /// Index of the [`Value::Marker`] which was rejected, if that was the failure.
+    pub(crate) fn marker(&self) -> Option<usize> {
+        self.marker
+    }
+
+    /// Validation errors of the scalar type rejected during deserialization,
+    /// reported at the path of its value.
+    pub(crate) fn rejection(&self) -> Option<ValidationErrors> {
+        let error = rejection(&self.message)?;
+        let mut errors = ValidationErrors::new();
+        insert_error(&mut errors, &self.path, error);
+        Some(errors)
+    }
+
+    /// Convert to the crate error with `f`, unless a scalar type was rejected.
+    pub(crate) fn into_error<F>(self, f: F) -> crate::error::Error
+    where
+        F: FnOnce(Error) -> crate::error::Error,
+    {
+        match self.rejection() {
+            Some(errors) => crate::error::Error::Validate(errors),
+            None => f(self),
+        }
+    }
+
+    fn at(mut self, segment: Segment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error {
+            message: msg.to_string(),
+            path: Vec::new(),
+            marker: None,
+        }
+    }
+
+    fn invalid_type(unexp: de::Unexpected<'_>, exp: &dyn de::Expected) -> Self {
+        Error {
+            message: format!("invalid type: {unexp}, expected {exp}"),
+            path: Vec::new(),
+            marker: marker_index(&unexp),
+        }
+    }
+
+    fn invalid_value(unexp: de::Unexpected<'_>, exp: &dyn de::Expected) -> Self {
+        Error {
+            message: format!("invalid value: {unexp}, expected {exp}"),
+            path: Vec::new(),
+            marker: marker_index(&unexp),
+        }
+    }
+}
+
+/// Value of a single request parameter.
+#[derive(Clone)]
+pub(crate) enum Value {
+    /// Raw string from the path or query, parsed according to the type requested by the visitor.
+    Str(String),
+    /// Already typed value from the JSON body.
+    Json(serde_json::Value),
+    /// Several raw values of a repeated or comma-separated query parameter.
+    Seq(Vec<Value>),
+    /// Nested raw values, e.g. `user[name]=john` query parameters.
+    Map(Vec<(String, Value)>),
+    /// Stand-in for a probed value, which only `IgnoredAny` and buffering of
+    /// self-describing content accept. Other visitors reject it telling its
+    /// index, see [`Error::marker`].
+    Marker([u8; 9]),
+}
+
+impl Value {
+    /// Convert a tree of strings, like the one parsed by `serde_qs`, to raw values.
+    pub(crate) fn from_tree(value: serde_json::Value) -> Value {
+        match value {
+            serde_json::Value::String(s) => Value::Str(s),
+            serde_json::Value::Array(items) => {
+                Value::Seq(items.into_iter().map(Value::from_tree).collect())
+            }
+            serde_json::Value::Object(entries) => Value::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, Value::from_tree(value)))
+                    .collect(),
+            ),
+            value => Value::Json(value),
+        }
+    }
+
+    /// Convert JSON value, keeping its scalars typed, but exposing entries of
+    /// objects and arrays.
+    pub(crate) fn from_json(value: serde_json::Value) -> Value {
+        match value {
+            serde_json::Value::Array(items) => {
+                Value::Seq(items.into_iter().map(Value::from_json).collect())
+            }
+            serde_json::Value::Object(entries) => Value::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, Value::from_json(value)))
+                    .collect(),
+            ),
+            value => Value::Json(value),
+        }
+    }
+
+    /// Replace values at the paths of `marks` with [`Value::Marker`] of the
+    /// paired index.
+    pub(crate) fn mark(&mut self, marks: &[(&[Segment], usize)]) {
+        if let Some((_, index)) = marks.iter().find(|(path, _)| path.is_empty()) {
+            *self = Value::Marker(marker(*index));
+            return;
+        }
+        match self {
+            Value::Map(entries) => {
+                let mut nested: HashMap<&str, Vec<(&[Segment], usize)>> = HashMap::new();
+                for (path, index) in marks {
+                    if let [Segment::Key(key), rest @ ..] = path {
+                        nested.entry(key).or_default().push((rest, *index));
+                    }
+                }
+                for (key, value) in entries {
+                    if let Some(marks) = nested.get(key.as_str()) {
+                        value.mark(marks);
+                    }
+                }
+            }
+            Value::Seq(items) => {
+                let mut nested: HashMap<usize, Vec<(&[Segment], usize)>> = HashMap::new();
+                for (path, index) in marks {
+                    if let [Segment::Index(i), rest @ ..] = path {
+                        nested.entry(*i).or_default().push((rest, *index));
+                    }
+                }
+                for (i, item) in items.iter_mut().enumerate() {
+                    if let Some(marks) = nested.get(&i) {
+                        item.mark(marks);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn unexpected(&self) -> de::Unexpected<'_> {
+        match self {
+            Value::Str(s) => de::Unexpected::Str(s),
+            Value::Json(_) => de::Unexpected::Other("json value"),
+            Value::Seq(_) => de::Unexpected::Seq,
+            Value::Map(_) => de::Unexpected::Map,
+            Value::Marker(bytes) => de::Unexpected::Bytes(bytes),
+        }
+    }
+}
+
+/// Paths of the map entries deserialized as self-describing content, which
+/// serde buffers e.g. for `#[serde(flatten)]` fields and internally tagged
+/// enums.
+#[derive(Clone, Default)]
+pub(crate) struct Trace {
+    path: Vec<Segment>,
+    buffered: Rc<RefCell<Vec<Vec<Segment>>>>,
+}
+
+impl Trace {
+    fn child(&self, segment: Segment) -> Trace {
+        let mut path = self.path.clone();
+        path.push(segment);
+        Trace {
+            path,
+            buffered: self.buffered.clone(),
+        }
+    }
+
+    /// Recorded paths.
+    pub(crate) fn buffered(&self) -> Vec<Vec<Segment>> {
+        self.buffered.borrow().clone()
+    }
+}
+
+/// Deserialize `T` from a list of named request parameters.
+///
+/// In `lenient` mode raw strings are coerced to numbers and booleans even
+/// when the type is not known upfront, like for `#[serde(flatten)]` fields
+/// and internally tagged enums. Strings of such fields that look like
+/// numbers or booleans can't be deserialized as strings then.
+pub(crate) fn from_pairs<T>(pairs: Vec<(String, Value)>, lenient: bool) -> Result<T, Error>
+where
+    T: de::DeserializeOwned,
+{
+    from_value(Value::Map(pairs), lenient, None)
+}
+
+/// Deserialize `T` from a value, recording buffered entries to `trace`.
+pub(crate) fn from_value<T>(value: Value, lenient: bool, trace: Option<Trace>) -> Result<T, Error>
+where
+    T: de::DeserializeOwned,
+{
+    T::deserialize(ValueDeserializer {
+        value,
+        lenient,
+        trace,
+    })
+}
+
+/// Report a scalar type rejected while deserializing `T` by another
+/// deserializer at the path of its value, found by deserializing `T` once
+/// more from `source`. Other errors are returned as is.
+pub(crate) fn locate<T, F>(
+    error: crate::error::Error,
+    lenient: bool,
+    source: F,
+) -> crate::error::Error
+where
+    T: de::DeserializeOwned,
+    F: FnOnce() -> Option<Value>,
+{
+    if !matches!(error, crate::error::Error::Validate(_)) {
+        return error;
+    }
+    source()
+        .and_then(|source| from_value::<T>(source, lenient, None).err())
+        .and_then(|e| e.rejection())
+        .map(crate::error::Error::Validate)
+        .unwrap_or(error)
+}
+
+/// Group values of repeated keys, splitting values of `comma_separated` keys
+/// by commas. Keys with a single value stay plain strings.
+pub(crate) fn group_pairs(
+    pairs: Vec<(String, String)>,
+    comma_separated: &[String],
+) -> Vec<(String, Value)> {
+    let mut grouped: Vec<(String, Vec<String>)> = Vec::new();
+    for (key, value) in pairs {
+        let values: Vec<String> = if comma_separated.contains(&key) {
+            value.split(',').map(str::to_owned).collect()
+        } else {
+            vec![value]
+        };
+        match grouped.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => existing.extend(values),
+            None => grouped.push((key, values)),
+        }
+    }
+    grouped
+        .into_iter()
+        .map(|(key, mut values)| match values.len() {
+            1 => (key, Value::Str(values.remove(0))),
+            _ => (
+                key,
+                Value::Seq(values.into_iter().map(Value::Str).collect()),
+            ),
+        })
+        .collect()
+}
+
+pub(crate) struct ValueDeserializer {
+    value: Value,
+    lenient: bool,
+    trace: Option<Trace>,
+}
+
+/// Items of a sequence, adding the index to the path of their errors.
+struct SeqAccess {
+    items: std::vec::IntoIter<(usize, Value)>,
+    lenient: bool,
+    trace: Option<Trace>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess {
+    type Error = Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Error> {
+        let Some((index, value)) = self.items.next() else {
+            return Ok(None);
+        };
+        let segment = Segment::Index(index);
+        let deserializer = ValueDeserializer {
+            value,
+            lenient: self.lenient,
+            trace: self.trace.as_ref().map(|t| t.child(segment.clone())),
+        };
+        seed.deserialize(deserializer)
+            .map(Some)
+            .map_err(|e| e.at(segment))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+fn visit_seq<'de, V: Visitor<'de>>(
+    items: Vec<(usize, Value)>,
+    lenient: bool,
+    trace: Option<Trace>,
+    visitor: V,
+) -> Result<V::Value, Error> {
+    let len = items.len();
+    let mut seq = SeqAccess {
+        items: items.into_iter(),
+        lenient,
+        trace,
+    };
+    let value = visitor.visit_seq(&mut seq)?;
+    match seq.items.len() {
+        0 => Ok(value),
+        remaining => Err(Error::invalid_length(
+            len,
+            &format!("{} elements in sequence", len - remaining).as_str(),
+        )),
+    }
+}
+
+/// Entries of a map, adding the key to the path of errors of their values.
+struct MapAccess {
+    entries: std::vec::IntoIter<(String, Value)>,
+    value: Option<(String, Value)>,
+    lenient: bool,
+    trace: Option<Trace>,
+}
+
+impl MapAccess {
+    fn new(entries: Vec<(String, Value)>, lenient: bool, trace: Option<Trace>) -> Self {
+        MapAccess {
+            entries: entries.into_iter(),
+            value: None,
+            lenient,
+            trace,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        let deserialized = seed.deserialize(key.clone().into_deserializer())?;
+        self.value = Some((key, value));
+        Ok(Some(deserialized))
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Error> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| Error::custom("value is missing"))?;
+        let segment = Segment::Key(key);
+        let deserializer = ValueDeserializer {
+            value,
+            lenient: self.lenient,
+            trace: self.trace.as_ref().map(|t| t.child(segment.clone())),
+        };
+        seed.deserialize(deserializer).map_err(|e| e.at(segment))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+fn visit_map<'de, V: Visitor<'de>>(
+    entries: Vec<(String, Value)>,
+    lenient: bool,
+    trace: Option<Trace>,
+    visitor: V,
+) -> Result<V::Value, Error> {
+    let len = entries.len();
+    let mut map = MapAccess::new(entries, lenient, trace);
+    let value = visitor.visit_map(&mut map)?;
+    match map.entries.len() {
+        0 => Ok(value),
+        remaining => Err(Error::invalid_length(
+            len,
+            &format!("{} elements in map", len - remaining).as_str(),
+        )),
+    }
+}
+
+/// Guess type of a raw string for the self-describing deserialization.
+fn visit_guessed<'de, V: Visitor<'de>>(s: String, visitor: V) -> Result<V::Value, Error> {
+    let is_number = s
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'));
+    match s.as_str() {
+        "true" => visitor.visit_bool(true),
+        "false" => visitor.visit_bool(false),
+        _ if is_number => match (s.parse::<u64>(), s.parse::<i64>(), s.parse::<f64>()) {
+            (Ok(v), _, _) => visitor.visit_u64(v),
+            (_, Ok(v), _) => visitor.visit_i64(v),
+            (_, _, Ok(v)) => visitor.visit_f64(v),
+            _ => visitor.visit_string(s),
+        },
+        _ => visitor.visit_string(s),
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.value {
+                    Value::Str(s) => match s.parse() {
+                        Ok(v) => visitor.$visit(v),
+                        Err(_) => Err(Error::invalid_value(de::Unexpected::Str(&s), &visitor)),
+                    },
+                    Value::Json(v) => v.$method(visitor).map_err(Error::custom),
+                    value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! deserialize_json {
+    ($($method:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.value {
+                    Value::Str(s) => visitor.visit_string(s),
+                    Value::Json(v) => v.$method(visitor).map_err(Error::custom),
+                    value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    deserialize_json! {
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_identifier,
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if let Some(trace) = &self.trace {
+            if let Some(Segment::Key(_)) = trace.path.last() {
+                trace.buffered.borrow_mut().push(trace.path.clone());
+            }
+        }
+        match self.value {
+            Value::Str(s) if self.lenient => visit_guessed(s, visitor),
+            Value::Str(s) => visitor.visit_string(s),
+            Value::Json(v) => v.deserialize_any(visitor).map_err(Error::custom),
+            Value::Seq(items) => visit_seq(
+                items.into_iter().enumerate().collect(),
+                self.lenient,
+                self.trace,
+                visitor,
+            ),
+            Value::Map(entries) => visit_map(entries, self.lenient, self.trace, visitor),
+            Value::Marker(bytes) => visitor.visit_bytes(&bytes),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v.deserialize_ignored_any(visitor).map_err(Error::custom),
+            _ => visitor.visit_unit(),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v.deserialize_option(visitor).map_err(Error::custom),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Str(_) => visitor.visit_unit(),
+            Value::Json(v) => v.deserialize_unit(visitor).map_err(Error::custom),
+            value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Str(_) => visitor.visit_unit(),
+            Value::Json(v) => v
+                .deserialize_unit_struct(name, visitor)
+                .map_err(Error::custom),
+            value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v
+                .deserialize_newtype_struct(name, visitor)
+                .map_err(Error::custom),
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Str(s) => visit_seq(vec![(0, Value::Str(s))], self.lenient, self.trace, visitor),
+            Value::Json(v) => v.deserialize_seq(visitor).map_err(Error::custom),
+            Value::Seq(items) => visit_seq(
+                items.into_iter().enumerate().collect(),
+                self.lenient,
+                self.trace,
+                visitor,
+            ),
+            // `a[0]=x&a[1]=y` query parameters
+            Value::Map(entries) => {
+                let mut items = Vec::with_capacity(entries.len());
+                for (key, value) in entries {
+                    match key.parse::<usize>() {
+                        Ok(index) => items.push((index, value)),
+                        Err(_) => return Err(Error::invalid_type(de::Unexpected::Map, &visitor)),
+                    }
+                }
+                items.sort_by_key(|(index, _)| *index);
+                // indices of the probed paths are the ones of the map
+                visit_seq(items, self.lenient, None, visitor)
+            }
+            value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v.deserialize_tuple(len, visitor).map_err(Error::custom),
+            _ => self.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v
+                .deserialize_tuple_struct(name, len, visitor)
+                .map_err(Error::custom),
+            _ => self.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v.deserialize_map(visitor).map_err(Error::custom),
+            Value::Map(entries) => visit_map(entries, self.lenient, self.trace, visitor),
+            value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Json(v) => v
+                .deserialize_struct(name, fields, visitor)
+                .map_err(Error::custom),
+            Value::Map(entries) => visit_map(entries, self.lenient, self.trace, visitor),
+            value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Str(s) => IntoDeserializer::<Error>::into_deserializer(s)
+                .deserialize_enum(name, variants, visitor),
+            Value::Json(v) => v
+                .deserialize_enum(name, variants, visitor)
+                .map_err(Error::custom),
+            // `{"variant": value}` of externally tagged enums
+            Value::Map(entries) if entries.len() == 1 => {
+                MapAccessDeserializer::new(MapAccess::new(entries, self.lenient, self.trace))
+                    .deserialize_enum(name, variants, visitor)
+            }
+            value => Err(Error::invalid_type(value.unexpected(), &visitor)),
+        }
+    }
+}
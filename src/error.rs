@@ -1,21 +1,56 @@
 //! Error declaration.
+use std::borrow::Cow;
+use std::fmt::Display;
+
 use actix_web::http::StatusCode;
 use actix_web::{HttpResponse, ResponseError};
 use thiserror::Error;
 use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
 
+use crate::constrained::FIELD;
+use crate::scalar::rejection;
+
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     #[error("Validation error: {0}")]
-    Validate(#[from] validator::ValidationErrors),
+    Validate(validator::ValidationErrors),
     #[error(transparent)]
-    Deserialize(#[from] DeserializeErrors),
+    Deserialize(DeserializeErrors),
     #[error("Payload error: {0}")]
-    JsonPayloadError(#[from] actix_web::error::JsonPayloadError),
+    JsonPayloadError(#[source] actix_web::error::JsonPayloadError),
     #[error("Url encoded error: {0}")]
-    UrlEncodedError(#[from] actix_web::error::UrlencodedError),
+    UrlEncodedError(#[source] actix_web::error::UrlencodedError),
     #[error("Query error: {0}")]
-    QsError(#[from] serde_qs::Error),
+    QsError(#[source] serde_qs::Error),
+    #[error("Payload error: {0}")]
+    PayloadError(#[from] actix_web::error::PayloadError),
+    #[error("Content type error")]
+    ContentType,
+    #[error("Verification error: {0}")]
+    Verify(actix_web::Error),
+    #[error("Extractor error: {0}")]
+    Extract(actix_web::Error),
+    #[cfg(feature = "csv")]
+    #[error("Csv row limit of {0} reached")]
+    TooManyRows(usize),
+    #[cfg(feature = "json-patch")]
+    #[error("Json patch error in operation {index}: {kind}")]
+    JsonPatch {
+        index: usize,
+        kind: JsonPatchErrorKind,
+    },
+}
+
+#[cfg(feature = "json-patch")]
+#[derive(Error, Debug)]
+pub enum JsonPatchErrorKind {
+    #[error("invalid operation: {0}")]
+    Invalid(serde_json::error::Error),
+    #[error("operation `{0}` is not allowed")]
+    NotAllowed(String),
+    #[error(transparent)]
+    Apply(json_patch::PatchErrorKind),
 }
 
 #[derive(Error, Debug)]
@@ -26,22 +61,98 @@ pub enum DeserializeErrors {
     DeserializeJson(serde_json::error::Error),
     #[error("Path deserialize error: {0}")]
     DeserializePath(serde::de::value::Error),
+    #[error("Params deserialize error: {0}")]
+    DeserializeParams(serde::de::value::Error),
+    #[cfg(feature = "csv")]
+    #[error("Csv deserialize error: {0}")]
+    DeserializeCsv(::csv::Error),
+}
+
+/// Errors of a value validated on top level, like `Json<Len<Vec<T>, 1, 10>>`,
+/// and of collection items, like `Json<Vec<Range<u32, 1, 10>>>`, are
+/// reported for the empty field.
+impl From<ValidationErrors> for Error {
+    fn from(mut errors: ValidationErrors) -> Self {
+        rename_field(&mut errors);
+        Error::Validate(errors)
+    }
+}
+
+fn rename_field(errors: &mut ValidationErrors) {
+    let errors = errors.errors_mut();
+    if let Some(kind) = errors.remove(FIELD) {
+        errors.insert(Cow::Borrowed(""), kind);
+    }
+    for kind in errors.values_mut() {
+        match kind {
+            ValidationErrorsKind::Field(_) => {}
+            ValidationErrorsKind::Struct(nested) => rename_field(nested),
+            ValidationErrorsKind::List(items) => {
+                items.values_mut().for_each(|item| rename_field(item))
+            }
+        }
+    }
+}
+
+/// Failures of validated scalar types, like `EmailAddress`, are reported
+/// as validation errors rather than deserialization ones. Extractors which
+/// can deserialize the value once more report them at the path of the
+/// field, others for the empty field.
+fn rejected(error: &impl Display) -> Option<Error> {
+    let error = rejection(&error.to_string())?;
+    let mut errors = ValidationErrors::new();
+    errors.add("", error);
+    Some(Error::Validate(errors))
+}
+
+impl From<DeserializeErrors> for Error {
+    fn from(error: DeserializeErrors) -> Self {
+        rejected(&error).unwrap_or(Error::Deserialize(error))
+    }
+}
+
+impl From<actix_web::error::JsonPayloadError> for Error {
+    fn from(error: actix_web::error::JsonPayloadError) -> Self {
+        rejected(&error).unwrap_or(Error::JsonPayloadError(error))
+    }
+}
+
+impl From<actix_web::error::UrlencodedError> for Error {
+    fn from(error: actix_web::error::UrlencodedError) -> Self {
+        rejected(&error).unwrap_or(Error::UrlEncodedError(error))
+    }
+}
+
+impl From<serde_qs::Error> for Error {
+    fn from(error: serde_qs::Error) -> Self {
+        rejected(&error).unwrap_or(Error::QsError(error))
+    }
 }
 
 impl From<serde_json::error::Error> for Error {
     fn from(error: serde_json::error::Error) -> Self {
-        Error::Deserialize(DeserializeErrors::DeserializeJson(error))
+        Error::from(DeserializeErrors::DeserializeJson(error))
     }
 }
 
 impl From<serde_urlencoded::de::Error> for Error {
     fn from(error: serde_urlencoded::de::Error) -> Self {
-        Error::Deserialize(DeserializeErrors::DeserializeQuery(error))
+        Error::from(DeserializeErrors::DeserializeQuery(error))
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<::csv::Error> for Error {
+    fn from(error: ::csv::Error) -> Self {
+        Error::from(DeserializeErrors::DeserializeCsv(error))
     }
 }
 
 impl ResponseError for Error {
     fn error_response(&self) -> HttpResponse {
+        if let Self::Verify(e) | Self::Extract(e) = self {
+            return e.error_response();
+        }
         HttpResponse::build(StatusCode::BAD_REQUEST).body(match self {
             Self::Validate(e) => {
                 format!(
@@ -67,7 +178,7 @@ pub fn flatten_errors(errors: &ValidationErrors) -> Vec<(u16, String, &Validatio
 }
 
 #[inline]
-fn _flatten_errors(
+pub(crate) fn _flatten_errors(
     errors: &ValidationErrors,
     path: Option<String>,
     indent: Option<u16>,
@@ -77,10 +188,12 @@ fn _flatten_errors(
         .iter()
         .flat_map(|(field, err)| {
             let indent = indent.unwrap_or(0);
-            let actual_path = path
-                .as_ref()
-                .map(|path| [path.as_str(), field].join("."))
-                .unwrap_or_else(|| field.to_string());
+            // errors of a list item itself are put under empty field
+            let actual_path = match &path {
+                Some(path) if field.is_empty() => path.clone(),
+                Some(path) => [path.as_str(), field].join("."),
+                None => field.to_string(),
+            };
             match err {
                 ValidationErrorsKind::Field(field_errors) => field_errors
                     .iter()
@@ -0,0 +1,35 @@
+//! Deserialization step of the extractors.
+use std::future::Future;
+
+use actix_web::dev::Payload;
+use actix_web::HttpRequest;
+
+use crate::error::Error;
+
+/// Deserialization step of the crate's extractors.
+///
+/// Extracts a value of `Inner` type from the request with the extractor's
+/// configuration (limits, content types, serde options), but without
+/// validation. Extractors with other validation strategies, like
+/// [`WithContext`](struct.WithContext.html), are built on top of it.
+pub trait Extractor {
+    /// Type of the extracted value.
+    type Inner;
+
+    /// Future returned by [`Extractor::extract`].
+    type Future: Future<Output = Result<Self::Inner, Error>>;
+
+    /// Deserialize value from the request without validation.
+    fn extract(req: &HttpRequest, payload: &mut Payload) -> Self::Future;
+
+    /// Convert an extraction error into a response error with
+    /// the error handler from extractor's configuration.
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error;
+
+    /// Whether the request carries nothing to extract, like an empty body
+    /// for [`Json`](struct.Json.html) or an empty query string for
+    /// [`Query`](struct.Query.html). Used by [`Optional`](struct.Optional.html).
+    fn is_absent(_req: &HttpRequest, _payload: &Payload) -> bool {
+        false
+    }
+}
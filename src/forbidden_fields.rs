@@ -0,0 +1,66 @@
+//! Forbidden fields policy.
+use serde_json::Value;
+use validator::{ValidationError, ValidationErrors};
+
+use crate::error::Error;
+use crate::unknown_fields::{insert_error, Segment};
+
+/// Reject JSON value setting any of the `fields` with `forbidden` validation
+/// error. Nested fields are separated by dots and apply to every item of
+/// sequences, e.g. `items.id`.
+pub(crate) fn check_value(value: &Value, fields: &[String]) -> Result<(), Error> {
+    let mut found = Vec::new();
+    for field in fields {
+        let segments: Vec<_> = field.split('.').collect();
+        find(value, &segments, &mut Vec::new(), &mut found);
+    }
+    reject(found)
+}
+
+/// Reject flat keys, e.g. of query parameters, setting any of the `fields`
+/// with `forbidden` validation error.
+pub(crate) fn check_keys<'a, I>(keys: I, fields: &[String]) -> Result<(), Error>
+where
+    I: IntoIterator<Item = &'a str> + Clone,
+{
+    let found = fields
+        .iter()
+        .filter(|field| keys.clone().into_iter().any(|key| key == field.as_str()))
+        .map(|field| vec![Segment::Key(field.clone())])
+        .collect();
+    reject(found)
+}
+
+fn find(value: &Value, segments: &[&str], path: &mut Vec<Segment>, found: &mut Vec<Vec<Segment>>) {
+    match (value, segments) {
+        (Value::Object(fields), [key, rest @ ..]) => {
+            if let Some(child) = fields.get(*key) {
+                path.push(Segment::Key(key.to_string()));
+                match rest {
+                    [] => found.push(path.clone()),
+                    rest => find(child, rest, path, found),
+                }
+                path.pop();
+            }
+        }
+        (Value::Array(items), segments) => {
+            for (index, item) in items.iter().enumerate() {
+                path.push(Segment::Index(index));
+                find(item, segments, path, found);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn reject(found: Vec<Vec<Segment>>) -> Result<(), Error> {
+    if found.is_empty() {
+        return Ok(());
+    }
+    let mut errors = ValidationErrors::new();
+    for path in &found {
+        insert_error(&mut errors, path, ValidationError::new("forbidden"));
+    }
+    Err(errors.into())
+}
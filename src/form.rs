@@ -1,11 +1,15 @@
 use actix_http::Payload;
-use actix_web::{dev::UrlEncoded, FromRequest, HttpRequest};
+use actix_web::{dev::UrlEncoded, error::UrlencodedError, FromRequest, HttpRequest};
 use futures::future::LocalBoxFuture;
 use futures::FutureExt;
-use serde::de::DeserializeOwned;
+use serde::de::{self, DeserializeOwned};
 use std::{ops::Deref, rc::Rc};
-use validator::Validate;
+use validator::{Validate, ValidationErrors};
 
+use crate::de::{from_pairs, Value};
+use crate::extractor::Extractor;
+use crate::forbidden_fields::check_keys;
+use crate::unknown_fields::{Tracked, UnknownFields};
 use crate::Error;
 
 /// Form can be used for extracting typed information and validation
@@ -52,6 +56,16 @@ impl<T> Form<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded,
+    /// so the value stays valid.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Validate + Clone,
+        F: FnOnce(&mut T),
+    {
+        crate::validated::modify(&mut self.0, f)
+    }
 }
 
 impl<T> AsRef<T> for Form<T> {
@@ -109,29 +123,59 @@ where
     #[inline]
     fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
         let req2 = req.clone();
-        let (limit, error_handler) = req
+        <Self as Extractor>::extract(req, payload)
+            .map(|res| match res {
+                Ok(data) => data.validate().map(|_| Form(data)).map_err(Error::from),
+                Err(e) => Err(e),
+            })
+            .map(move |res| res.map_err(|e| Self::error(e, &req2)))
+            .boxed_local()
+    }
+}
+
+impl<T> Extractor for Form<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    type Inner = T;
+    type Future = LocalBoxFuture<'static, Result<T, Error>>;
+
+    fn extract(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let (limit, unknown_fields, forbidden_fields) = req
             .app_data::<FormConfig>()
-            .map(|c| (c.limit, c.ehandler.clone()))
-            .unwrap_or((16_384, None));
+            .map(|c| (c.limit, c.unknown_fields, c.forbidden_fields.clone()))
+            .unwrap_or((16_384, UnknownFields::Ignore, Vec::new()));
+        let req = req.clone();
 
-        UrlEncoded::new(req, payload)
+        UrlEncoded::new(&req, payload)
             .limit(limit)
-            .map(|res: Result<T, _>| match res {
-                Ok(data) => data.validate().map(|_| Form(data)).map_err(Error::from),
-                Err(e) => Err(Error::from(e)),
-            })
-            .map(move |res| match res {
-                Err(e) => {
-                    if let Some(err) = error_handler {
-                        Err((*err)(e, &req2))
-                    } else {
-                        Err(e.into())
-                    }
-                }
-                Ok(item) => Ok(item),
+            .map(move |res: Result<Vec<(String, String)>, _>| {
+                let pairs = res.map_err(Error::from)?;
+                check_keys(pairs.iter().map(|(key, _)| key.as_str()), &forbidden_fields)?;
+                let pairs: Vec<_> = pairs.into_iter().map(|(k, v)| (k, Value::Str(v))).collect();
+                from_pairs::<Tracked<T>>(pairs.clone(), false)
+                    .map_err(|e| {
+                        e.into_error(|e| UrlencodedError::Parse(de::Error::custom(e)).into())
+                    })
+                    .map(|value| value.probe(unknown_fields, false, || Some(Value::Map(pairs))))
+                    .and_then(|value| value.check(unknown_fields, &req))
             })
             .boxed_local()
     }
+
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error {
+        match req
+            .app_data::<FormConfig>()
+            .and_then(|c| c.ehandler.as_ref())
+        {
+            Some(err) => (*err)(error, req),
+            None => error.into(),
+        }
+    }
+
+    fn is_absent(req: &HttpRequest, payload: &Payload) -> bool {
+        crate::body::is_empty(req, payload)
+    }
 }
 
 type ErrHandler = Rc<dyn Fn(Error, &HttpRequest) -> actix_web::Error>;
@@ -172,6 +216,8 @@ type ErrHandler = Rc<dyn Fn(Error, &HttpRequest) -> actix_web::Error>;
 pub struct FormConfig {
     limit: usize,
     ehandler: Option<ErrHandler>,
+    unknown_fields: UnknownFields,
+    forbidden_fields: Vec<String>,
 }
 
 impl FormConfig {
@@ -189,6 +235,25 @@ impl FormConfig {
         self.ehandler = Some(Rc::new(f));
         self
     }
+
+    /// Set policy for fields unknown to the deserialized type.
+    /// By default they are ignored.
+    pub fn unknown_fields(mut self, policy: UnknownFields) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Reject payload setting any of the listed fields with `forbidden`
+    /// validation error, even if the deserialized type has them, e.g. `id`
+    /// or `role`.
+    pub fn forbidden_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 impl Default for FormConfig {
@@ -196,6 +261,8 @@ impl Default for FormConfig {
         Self {
             limit: 16_384,
             ehandler: None,
+            unknown_fields: UnknownFields::Ignore,
+            forbidden_fields: Vec::new(),
         }
     }
 }
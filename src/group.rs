@@ -0,0 +1,162 @@
+//! Validation groups.
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::ValidationErrors;
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Validation rules of the group `G`, e.g. of creation or update of the same
+/// resource. Groups are marker types defined by the application.
+///
+/// [`Group`](struct.Group.html) extractor runs rules of its group instead of
+/// `Validate`.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web_validator::ValidateGroup;
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationError, ValidationErrors};
+///
+/// struct OnCreate;
+/// struct OnUpdate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Article {
+///     id: Option<u64>,
+///     #[validate(length(min = 1))]
+///     title: String,
+/// }
+///
+/// impl ValidateGroup<OnCreate> for Article {
+///     fn validate_group(&self) -> Result<(), ValidationErrors> {
+///         self.validate()?;
+///         if self.id.is_some() {
+///             let mut errors = ValidationErrors::new();
+///             errors.add("id", ValidationError::new("forbidden"));
+///             return Err(errors);
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// impl ValidateGroup<OnUpdate> for Article {
+///     fn validate_group(&self) -> Result<(), ValidationErrors> {
+///         self.validate()?;
+///         if self.id.is_none() {
+///             let mut errors = ValidationErrors::new();
+///             errors.add("id", ValidationError::new("required"));
+///             return Err(errors);
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait ValidateGroup<G> {
+    /// Validate the value with rules of the group.
+    fn validate_group(&self) -> Result<(), ValidationErrors>;
+}
+
+/// Extract typed information with any of the crate's extractors and validate
+/// it with [`ValidateGroup<G>`](trait.ValidateGroup.html) rules, e.g.
+/// `Group<Json<Article>, OnCreate>`.
+///
+/// Errors are reported through the inner extractor's configuration.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{Group, Json, ValidateGroup};
+/// use serde::Deserialize;
+/// use validator::{ValidationError, ValidationErrors};
+///
+/// struct OnCreate;
+///
+/// #[derive(Deserialize)]
+/// struct Article {
+///     id: Option<u64>,
+///     title: String,
+/// }
+///
+/// impl ValidateGroup<OnCreate> for Article {
+///     fn validate_group(&self) -> Result<(), ValidationErrors> {
+///         if self.id.is_some() {
+///             let mut errors = ValidationErrors::new();
+///             errors.add("id", ValidationError::new("forbidden"));
+///             return Err(errors);
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// async fn create(article: Group<Json<Article>, OnCreate>) -> String {
+///     format!("Created {}", article.title)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/articles").route(web::post().to(create)));
+/// }
+/// ```
+pub struct Group<E: Extractor, G>(pub E::Inner, PhantomData<G>);
+
+impl<E: Extractor, G> Group<E, G> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> E::Inner {
+        self.0
+    }
+}
+
+impl<E: Extractor, G> AsRef<E::Inner> for Group<E, G> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E: Extractor, G> Deref for Group<E, G> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E: Extractor, G> fmt::Debug for Group<E, G>
+where
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E, G> FromRequest for Group<E, G>
+where
+    E: Extractor + 'static,
+    E::Inner: ValidateGroup<G>,
+    E::Future: 'static,
+    G: 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| {
+                res.and_then(|value| {
+                    value.validate_group()?;
+                    Ok::<_, Error>(Group(value, PhantomData))
+                })
+                .map_err(|e| E::error(e, &req))
+            })
+            .boxed_local()
+    }
+}
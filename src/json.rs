@@ -3,15 +3,25 @@ use core::fmt::Debug;
 use std::ops::Deref;
 use std::sync::Arc;
 
-use actix_web::dev::{JsonBody, Payload};
-use actix_web::FromRequest;
-use actix_web::HttpRequest;
-use futures::future::{FutureExt, LocalBoxFuture};
+use actix_web::body::BoxBody;
+use actix_web::dev::Payload;
+use actix_web::error::{JsonPayloadError, PayloadError};
+use actix_web::http::StatusCode;
+use actix_web::web::Bytes;
+use actix_web::{FromRequest, HttpMessage};
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use futures::future::{ready, FutureExt, LocalBoxFuture};
 // use futures_util::future::{LocalBoxFuture, Try};
 use serde::de::DeserializeOwned;
-use validator::Validate;
+use serde::Serialize;
+use validator::{Validate, ValidationErrors};
 
-use crate::error::Error;
+use crate::body::read_body;
+use crate::de::{locate, Value};
+use crate::error::{flatten_errors, Error};
+use crate::extractor::Extractor;
+use crate::forbidden_fields::check_value;
+use crate::unknown_fields::{Tracked, UnknownFields};
 
 /// Json can be used for exstracting typed information and validation
 /// from request's payload.
@@ -57,6 +67,16 @@ impl<T> Json<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded,
+    /// so the value stays valid.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Validate + Clone,
+        F: FnOnce(&mut T),
+    {
+        crate::validated::modify(&mut self.0, f)
+    }
 }
 
 impl<T> AsRef<T> for Json<T> {
@@ -121,38 +141,176 @@ where
     #[inline]
     fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
         let req2 = req.clone();
-        let (limit, err, ctype) = req
-            .app_data::<JsonConfig>()
-            .map(|c| (c.limit, c.ehandler.clone(), c.content_type.clone()))
-            .unwrap_or((32768, None, None));
-
-        JsonBody::new(req, payload, ctype.as_deref(), false)
-            .limit(limit)
-            .map(|res: Result<T, _>| match res {
+        <Self as Extractor>::extract(req, payload)
+            .map(|res| match res {
                 Ok(data) => data.validate().map(|_| Json(data)).map_err(Error::from),
-                Err(e) => Err(Error::from(e)),
-            })
-            .map(move |res| match res {
-                Ok(data) => Ok(data),
-                Err(e) => {
-                    log::debug!(
-                        "Failed to deserialize Json from payload. \
-                         Request path: {}",
-                        req2.path()
-                    );
-                    if let Some(err) = err {
-                        Err((*err)(e, &req2))
-                    } else {
-                        Err(e.into())
-                    }
-                }
+                Err(e) => Err(e),
             })
+            .map(move |res| res.map_err(|e| Self::error(e, &req2)))
+            .boxed_local()
+    }
+}
+
+impl<T> Extractor for Json<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    type Inner = T;
+    type Future = LocalBoxFuture<'static, Result<T, Error>>;
+
+    fn extract(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let unknown_fields = req
+            .app_data::<JsonConfig>()
+            .map(|c| c.unknown_fields)
+            .unwrap_or_default();
+        let req = req.clone();
+
+        read_json_body(&req, payload)
+            .map(move |res| res.and_then(|body| from_json_body(&body, unknown_fields, &req)))
             .boxed_local()
     }
+
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error {
+        log::debug!(
+            "Failed to deserialize Json from payload. \
+             Request path: {}",
+            req.path()
+        );
+        match req
+            .app_data::<JsonConfig>()
+            .and_then(|c| c.ehandler.as_ref())
+        {
+            Some(err) => (*err)(error, req),
+            None => error.into(),
+        }
+    }
+
+    fn is_absent(req: &HttpRequest, payload: &Payload) -> bool {
+        crate::body::is_empty(req, payload)
+    }
+}
+
+/// Json can be used as a responder, validating the value before
+/// serializing it.
+///
+/// Invalid value is a bug in the handler, so it is logged and rendered as
+/// `500 Internal Server Error`: with an empty body in release builds, and
+/// with the list of failed fields in debug builds.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web_validator::Json;
+/// use serde::Serialize;
+/// use validator::Validate;
+///
+/// #[derive(Serialize, Validate)]
+/// struct User {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// async fn index() -> Json<User> {
+///     Json(User { email: "john@example.com".to_owned() })
+/// }
+/// ```
+impl<T> Responder for Json<T>
+where
+    T: Serialize + Validate,
+{
+    type Body = BoxBody;
+
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        if let Err(errors) = self.0.validate() {
+            let report = flatten_errors(&errors)
+                .iter()
+                .map(|(_, field, err)| format!("\t{field}: {err}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            log::error!(
+                "Invalid Json response. Request path: {}\n{}",
+                req.path(),
+                report
+            );
+            let mut response = HttpResponse::build(StatusCode::INTERNAL_SERVER_ERROR);
+            if cfg!(debug_assertions) {
+                return response.body(format!("Validation errors in response fields:\n{report}"));
+            }
+            return response.finish();
+        }
+
+        match serde_json::to_string(&self.0) {
+            Ok(body) => HttpResponse::Ok()
+                .content_type(mime::APPLICATION_JSON)
+                .body(body),
+            Err(err) => HttpResponse::from_error(JsonPayloadError::Serialize(err)),
+        }
+    }
+}
+
+/// Read raw JSON body, checking content type, limit, verifier and forbidden
+/// fields from [`JsonConfig`]. Missing content type is accepted, other
+/// than JSON ones only when the content type predicate is set and accepts
+/// them.
+pub(crate) fn read_json_body(
+    req: &HttpRequest,
+    payload: &mut Payload,
+) -> LocalBoxFuture<'static, Result<Bytes, Error>> {
+    let config = req.app_data::<JsonConfig>().cloned().unwrap_or_default();
+    if let (Some(predicate), Ok(Some(mime))) = (&config.content_type, req.mime_type()) {
+        if mime.subtype() != mime::JSON && mime.suffix() != Some(mime::JSON) && !predicate(mime) {
+            return ready(Err(JsonPayloadError::ContentType.into())).boxed_local();
+        }
+    }
+    let payload = payload.take();
+    let req = req.clone();
+
+    async move {
+        let body = read_body(req.clone(), payload, config.limit)
+            .await
+            .map_err(|e| match e {
+                PayloadError::Overflow => JsonPayloadError::Overflow {
+                    limit: config.limit,
+                },
+                e => JsonPayloadError::Payload(e),
+            })?;
+        if let Some(verifier) = &config.verifier {
+            verifier(&req, &body).map_err(Error::Verify)?;
+        }
+        if !config.forbidden_fields.is_empty() {
+            let value = serde_json::from_slice(&body).map_err(JsonPayloadError::Deserialize)?;
+            check_value(&value, &config.forbidden_fields)?;
+        }
+        Ok(body)
+    }
+    .boxed_local()
+}
+
+/// Deserialize `T` from the raw JSON body, applying the unknown fields policy.
+pub(crate) fn from_json_body<T>(
+    body: &[u8],
+    unknown_fields: UnknownFields,
+    req: &HttpRequest,
+) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice::<Tracked<T>>(body)
+        .map_err(|e| {
+            locate::<T, _>(JsonPayloadError::Deserialize(e).into(), false, || {
+                serde_json::from_slice(body).ok().map(Value::from_json)
+            })
+        })?
+        .probe(unknown_fields, false, || {
+            serde_json::from_slice(body).ok().map(Value::from_json)
+        })
+        .check(unknown_fields, req)
 }
 
 type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
 
+type Verifier = Arc<dyn Fn(&HttpRequest, &Bytes) -> Result<(), actix_web::Error> + Send + Sync>;
+
 /// Json extractor configuration
 ///
 /// ```rust
@@ -189,9 +347,12 @@ type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + S
 /// ```
 #[derive(Clone)]
 pub struct JsonConfig {
-    limit: usize,
-    ehandler: Option<ErrHandler>,
-    content_type: Option<Arc<dyn Fn(mime::Mime) -> bool + Send + Sync>>,
+    pub(crate) limit: usize,
+    pub(crate) ehandler: Option<ErrHandler>,
+    pub(crate) content_type: Option<Arc<dyn Fn(mime::Mime) -> bool + Send + Sync>>,
+    pub(crate) verifier: Option<Verifier>,
+    pub(crate) unknown_fields: UnknownFields,
+    pub(crate) forbidden_fields: Vec<String>,
 }
 
 impl JsonConfig {
@@ -210,7 +371,8 @@ impl JsonConfig {
         self
     }
 
-    /// Set predicate for allowed content types
+    /// Set predicate for allowed content types. Requests without content
+    /// type and with JSON ones are always accepted.
     pub fn content_type<F>(mut self, predicate: F) -> Self
     where
         F: Fn(mime::Mime) -> bool + Send + Sync + 'static,
@@ -218,6 +380,36 @@ impl JsonConfig {
         self.content_type = Some(Arc::new(predicate));
         self
     }
+
+    /// Set policy for fields unknown to the deserialized type.
+    /// By default they are ignored.
+    pub fn unknown_fields(mut self, policy: UnknownFields) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Set a hook verifying raw payload before deserialization, e.g. checking
+    /// a webhook's signature. The error it returns is reported as
+    /// `Error::Verify` and rendered with its own response.
+    pub fn verifier<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&HttpRequest, &Bytes) -> Result<(), actix_web::Error> + Send + Sync + 'static,
+    {
+        self.verifier = Some(Arc::new(f));
+        self
+    }
+
+    /// Reject payload setting any of the listed fields with `forbidden`
+    /// validation error, even if the deserialized type has them, e.g. `id`
+    /// or `role`. Nested fields are separated by dots, e.g. `items.id`.
+    pub fn forbidden_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 impl Default for JsonConfig {
@@ -226,6 +418,9 @@ impl Default for JsonConfig {
             limit: 32768,
             ehandler: None,
             content_type: None,
+            verifier: None,
+            unknown_fields: UnknownFields::Ignore,
+            forbidden_fields: Vec::new(),
         }
     }
 }
@@ -0,0 +1,255 @@
+//! JSON Patch extractor.
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use ::json_patch::{Patch, PatchOperation};
+use actix_web::dev::Payload;
+use actix_web::error::JsonPayloadError;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::body::read_body;
+use crate::error::{Error, JsonPatchErrorKind};
+
+/// Extract a [JSON Patch](https://www.rfc-editor.org/rfc/rfc6902) for `T`
+/// from the `application/json-patch+json` request's body.
+///
+/// Every operation is checked while extracting: it must be well-formed,
+/// with valid JSON pointers, and its `op` must be allowed by
+/// [**JsonPatchConfig**](struct.JsonPatchConfig.html). The patch is applied
+/// onto an existing `T` with
+/// [`apply_to`](struct.JsonPatch.html#method.apply_to), which validates
+/// the result.
+///
+/// Failures of a particular operation are reported as `Error::JsonPatch`
+/// with the index of the operation.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{JsonPatch, JsonPatchConfig};
+/// use serde::{Deserialize, Serialize};
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Serialize, Validate)]
+/// struct Profile {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// async fn index(patch: JsonPatch<Profile>) -> actix_web::Result<String> {
+///     let profile = Profile { username: "john".to_owned() };
+///     let profile = patch.apply_to(&profile)?;
+///     Ok(format!("Welcome {}!", profile.username))
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/profile")
+///             .app_data(JsonPatchConfig::default().allowed_operations(["test", "replace"]))
+///             .route(web::patch().to(index)));
+/// }
+/// ```
+pub struct JsonPatch<T> {
+    patch: Patch,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonPatch<T> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> Patch {
+        self.patch
+    }
+
+    /// Apply the patch onto `target` and validate the result.
+    pub fn apply_to(&self, target: &T) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned + Validate,
+    {
+        let mut value = serde_json::to_value(target)?;
+        ::json_patch::patch(&mut value, &self.patch).map_err(|e| Error::JsonPatch {
+            index: e.operation,
+            kind: JsonPatchErrorKind::Apply(e.kind),
+        })?;
+        let result: T = serde_json::from_value(value)?;
+        result.validate()?;
+        Ok(result)
+    }
+}
+
+/// Parse operations one by one, so that errors point to the failed one.
+fn parse_operations(operations: Vec<Value>, allowed: Option<&[String]>) -> Result<Patch, Error> {
+    operations
+        .into_iter()
+        .enumerate()
+        .map(|(index, operation)| {
+            let op = operation.get("op").and_then(Value::as_str);
+            if let (Some(op), Some(allowed)) = (op, allowed) {
+                if !allowed.iter().any(|allowed| allowed == op) {
+                    let kind = JsonPatchErrorKind::NotAllowed(op.to_owned());
+                    return Err(Error::JsonPatch { index, kind });
+                }
+            }
+            serde_json::from_value::<PatchOperation>(operation).map_err(|e| Error::JsonPatch {
+                index,
+                kind: JsonPatchErrorKind::Invalid(e),
+            })
+        })
+        .collect::<Result<_, _>>()
+        .map(Patch)
+}
+
+impl<T> AsRef<Patch> for JsonPatch<T> {
+    fn as_ref(&self) -> &Patch {
+        &self.patch
+    }
+}
+
+impl<T> Deref for JsonPatch<T> {
+    type Target = [PatchOperation];
+
+    fn deref(&self) -> &[PatchOperation] {
+        &self.patch
+    }
+}
+
+impl<T> fmt::Debug for JsonPatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.patch.fmt(f)
+    }
+}
+
+impl<T: 'static> FromRequest for JsonPatch<T> {
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let config = req
+            .app_data::<JsonPatchConfig>()
+            .cloned()
+            .unwrap_or_default();
+        let is_json_patch = req
+            .mime_type()
+            .ok()
+            .flatten()
+            .is_some_and(|mime| mime.essence_str() == "application/json-patch+json");
+        let payload = payload.take();
+        let req = req.clone();
+
+        async move {
+            let result = async {
+                if !is_json_patch {
+                    return Err(JsonPayloadError::ContentType.into());
+                }
+                let body = read_body(req.clone(), payload, config.limit).await?;
+                let operations: Vec<Value> = serde_json::from_slice(&body)?;
+                parse_operations(operations, config.allowed_ops.as_deref())
+            }
+            .await;
+            result
+                .map(|patch| JsonPatch {
+                    patch,
+                    target: PhantomData,
+                })
+                .map_err(|e| {
+                    log::debug!(
+                        "Failed to deserialize json patch from payload. \
+                         Request path: {}",
+                        req.path()
+                    );
+                    match config.ehandler {
+                        Some(err) => (*err)(e, &req),
+                        None => e.into(),
+                    }
+                })
+        }
+        .boxed_local()
+    }
+}
+
+type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
+
+/// JsonPatch extractor configuration
+///
+/// ```rust
+/// use actix_web::{error, web, App, HttpResponse};
+/// use actix_web_validator::{JsonPatch, JsonPatchConfig};
+/// use serde::{Deserialize, Serialize};
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Serialize, Validate)]
+/// struct Profile {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// async fn index(patch: JsonPatch<Profile>) -> String {
+///     format!("{} operations", patch.len())
+/// }
+///
+/// fn main() {
+///     let json_patch_config = JsonPatchConfig::default()
+///         .limit(4096)
+///         .allowed_operations(["add", "remove", "replace"])
+///         .error_handler(|err, req| {  // <- create custom error response
+///             error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+///         });
+///     let app = App::new().service(
+///         web::resource("/profile")
+///             .app_data(json_patch_config)
+///             .route(web::patch().to(index))
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct JsonPatchConfig {
+    limit: usize,
+    ehandler: Option<ErrHandler>,
+    allowed_ops: Option<Vec<String>>,
+}
+
+impl JsonPatchConfig {
+    /// Change max size of payload. By default max size is 32Kb
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set custom error handler
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        self.ehandler = Some(Arc::new(f));
+        self
+    }
+
+    /// Allow only listed operations, e.g. `["test", "replace"]`.
+    /// By default all operations are allowed.
+    pub fn allowed_operations<I, S>(mut self, operations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_ops = Some(operations.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl Default for JsonPatchConfig {
+    fn default() -> Self {
+        JsonPatchConfig {
+            limit: 32768,
+            ehandler: None,
+            allowed_ops: None,
+        }
+    }
+}
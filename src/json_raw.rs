@@ -0,0 +1,126 @@
+//! Json extractor keeping raw payload.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::web::Bytes;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+use crate::extractor::Extractor;
+use crate::json::{from_json_body, read_json_body, Json, JsonConfig};
+
+/// Extract typed information from request's payload like
+/// [`Json`](struct.Json.html), keeping the raw payload next to
+/// the validated value.
+///
+/// Useful for webhooks, which sign the exact bytes of the payload. The
+/// signature can be checked by hand with
+/// [`raw`](struct.JsonWithRaw.html#method.raw) or before deserialization
+/// with the [`JsonConfig::verifier`](struct.JsonConfig.html#method.verifier)
+/// hook.
+///
+/// [**JsonConfig**](struct.JsonConfig.html) allows to configure extraction
+/// process.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{error, web, App};
+/// use actix_web_validator::{JsonConfig, JsonWithRaw};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Event {
+///     #[validate(length(min = 1))]
+///     kind: String,
+/// }
+///
+/// async fn index(event: JsonWithRaw<Event>) -> String {
+///     format!("{} event of {} bytes", event.kind, event.raw().len())
+/// }
+///
+/// fn main() {
+///     let json_config = JsonConfig::default().verifier(|req, body| {
+///         match req.headers().get("x-signature") {
+///             Some(signature) => Ok(()),  // <- check signature of `body` here
+///             None => Err(error::ErrorUnauthorized("Missing signature")),
+///         }
+///     });
+///     let app = App::new().service(
+///         web::resource("/webhook")
+///             .app_data(json_config)
+///             .route(web::post().to(index)));
+/// }
+/// ```
+pub struct JsonWithRaw<T> {
+    value: T,
+    raw: Bytes,
+}
+
+impl<T> JsonWithRaw<T> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Deconstruct to an inner value and raw payload
+    pub fn into_parts(self) -> (T, Bytes) {
+        (self.value, self.raw)
+    }
+
+    /// Raw payload the value was deserialized from.
+    pub fn raw(&self) -> &Bytes {
+        &self.raw
+    }
+}
+
+impl<T> AsRef<T> for JsonWithRaw<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Deref for JsonWithRaw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for JsonWithRaw<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> FromRequest for JsonWithRaw<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let unknown_fields = req
+            .app_data::<JsonConfig>()
+            .map(|c| c.unknown_fields)
+            .unwrap_or_default();
+        let req = req.clone();
+        read_json_body(&req, payload)
+            .map(move |res| {
+                res.and_then(|raw| {
+                    let value: T = from_json_body(&raw, unknown_fields, &req)?;
+                    value.validate()?;
+                    Ok(JsonWithRaw { value, raw })
+                })
+                .map_err(|e| <Json<T> as Extractor>::error(e, &req))
+            })
+            .boxed_local()
+    }
+}
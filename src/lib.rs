@@ -38,15 +38,64 @@
 //!        web::resource("/index.html").route(web::get().to(index))); // <- use `Query` extractor
 //! }
 //! ```
+mod async_validate;
+mod batch;
+mod body;
+mod checked;
+mod constrained;
+mod context;
+#[cfg(feature = "csv")]
+mod csv;
+mod de;
 pub mod error;
+mod extractor;
+mod forbidden_fields;
 mod form;
+mod group;
 mod json;
+#[cfg(feature = "json-patch")]
+mod json_patch;
+mod json_raw;
+mod merge_patch;
+pub mod normalize;
+mod optional;
+mod params;
 mod path;
+mod qsform;
 mod qsquery;
 mod query;
+mod scalar;
+mod unknown_fields;
+mod unvalidated;
+mod valid;
+mod validate_request;
+mod validated;
+#[cfg(feature = "csv")]
+pub use crate::csv::*;
+pub use async_validate::*;
+pub use batch::*;
+pub use checked::*;
+pub use constrained::*;
+pub use context::*;
 pub use error::Error;
+pub use extractor::*;
 pub use form::*;
+pub use group::*;
 pub use json::*;
+#[cfg(feature = "json-patch")]
+pub use json_patch::*;
+pub use json_raw::*;
+pub use merge_patch::*;
+pub use normalize::{Normalize, Normalized};
+pub use optional::*;
+pub use params::*;
 pub use path::*;
+pub use qsform::*;
 pub use qsquery::*;
 pub use query::*;
+pub use scalar::{BoundedU64, EmailAddress, NonEmptyString, Slug, Uuid};
+pub use unknown_fields::{unknown_fields_header, UnknownFields, UnknownFieldsWarning};
+pub use unvalidated::*;
+pub use valid::*;
+pub use validate_request::*;
+pub use validated::*;
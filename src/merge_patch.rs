@@ -0,0 +1,317 @@
+//! JSON Merge Patch extractor.
+use std::fmt;
+use std::marker::PhantomData;
+
+use actix_web::dev::Payload;
+use actix_web::error::JsonPayloadError;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use crate::body::read_body;
+use crate::de::{self, locate};
+use crate::error::Error;
+use crate::forbidden_fields::check_value;
+use crate::json::JsonConfig;
+use crate::unknown_fields::{insert_error, Segment, Tracked, UnknownFields};
+
+/// Extract a [JSON Merge Patch](https://www.rfc-editor.org/rfc/rfc7386)
+/// of the resource `T` from the `application/merge-patch+json` request's
+/// body and validate the supplied fields with `T`'s own rules.
+///
+/// The patch is merged onto `T::default()` and the result is validated, but
+/// only errors of the fields present in the patch are reported. `null`
+/// members removing a field required by `T` are reported with `required`
+/// validation error. Field names are matched with the patch members as is or
+/// renamed by a `#[serde(rename_all)]` convention. Errors of the fields which
+/// can't be matched, e.g. renamed one by one, are always reported, while
+/// struct-level rules are only checked by
+/// [`apply_to`](struct.MergePatch.html#method.apply_to), which applies the
+/// patch onto an existing resource and validates the whole result.
+///
+/// Payload limit, unknown and forbidden fields policies and error handler
+/// are taken from [**JsonConfig**](struct.JsonConfig.html).
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::MergePatch;
+/// use serde::{Deserialize, Serialize};
+/// use validator::Validate;
+///
+/// #[derive(Default, Deserialize, Serialize, Validate)]
+/// struct Profile {
+///     #[validate(length(min = 3))]
+///     username: String,
+///     #[validate(range(min = 18))]
+///     age: u8,
+/// }
+///
+/// async fn index(patch: MergePatch<Profile>) -> actix_web::Result<String> {
+///     let profile = Profile { username: "john".to_owned(), age: 42 };
+///     let profile = patch.apply_to(&profile)?;
+///     Ok(format!("Welcome {}!", profile.username))
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/profile").route(web::patch().to(index)));
+/// }
+/// ```
+pub struct MergePatch<T> {
+    raw: Value,
+    resource: PhantomData<fn() -> T>,
+}
+
+impl<T> MergePatch<T> {
+    /// Deconstruct to the patch document
+    pub fn into_inner(self) -> Value {
+        self.raw
+    }
+
+    /// Patch document as it was sent by the client.
+    pub fn raw(&self) -> &Value {
+        &self.raw
+    }
+}
+
+impl<T> MergePatch<T>
+where
+    T: Serialize + DeserializeOwned + Validate,
+{
+    /// Apply the patch onto `target` and validate the result.
+    pub fn apply_to(&self, target: &T) -> Result<T, Error> {
+        let mut value = serde_json::to_value(target)?;
+        merge(&mut value, &self.raw);
+        let result: T = serde_json::from_value(value.clone())
+            .map_err(|e| locate::<T, _>(e.into(), false, || Some(de::Value::from_json(value))))?;
+        result.validate()?;
+        Ok(result)
+    }
+}
+
+/// Validate fields of the patch with `T`'s rules, merging it onto
+/// `T::default()`, and apply the unknown fields policy.
+fn check<T>(raw: &Value, unknown_fields: UnknownFields, req: &HttpRequest) -> Result<(), Error>
+where
+    T: Serialize + DeserializeOwned + Validate + Default,
+{
+    let default = serde_json::to_value(T::default())?;
+
+    let mut errors = ValidationErrors::new();
+    let mut path = Vec::new();
+    for_each_null(raw, &mut path, &mut |path| {
+        let mut value = default.clone();
+        remove(&mut value, path);
+        if serde_json::from_value::<T>(value).is_err() {
+            insert_error(&mut errors, path, ValidationError::new("required"));
+        }
+    });
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    let mut value = default;
+    merge(&mut value, raw);
+    let patched: T = serde_json::from_value::<Tracked<T>>(value.clone())
+        .map_err(|e| {
+            locate::<T, _>(e.into(), false, || {
+                Some(de::Value::from_json(value.clone()))
+            })
+        })?
+        .probe(unknown_fields, false, || Some(de::Value::from_json(value)))
+        .check(unknown_fields, req)?;
+    match (patched.validate(), raw) {
+        (Err(mut errors), Value::Object(members)) => {
+            let known = match serde_json::to_value(&patched)? {
+                Value::Object(known) => known,
+                _ => Map::new(),
+            };
+            retain_present(&mut errors, members, &known);
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors.into())
+            }
+        }
+        (result, _) => Ok(result?),
+    }
+}
+
+/// Call `f` with paths of `null` members of the patch.
+fn for_each_null<F>(patch: &Value, path: &mut Vec<Segment>, f: &mut F)
+where
+    F: FnMut(&[Segment]),
+{
+    if let Value::Object(members) = patch {
+        for (name, value) in members {
+            path.push(Segment::Key(name.clone()));
+            match value {
+                Value::Null => f(path),
+                value => for_each_null(value, path, f),
+            }
+            path.pop();
+        }
+    }
+}
+
+/// Remove member at `path` from `target`.
+fn remove(target: &mut Value, path: &[Segment]) {
+    match (target, path) {
+        (Value::Object(members), [Segment::Key(name)]) => {
+            members.remove(name);
+        }
+        (Value::Object(members), [Segment::Key(name), rest @ ..]) => {
+            if let Some(member) = members.get_mut(name) {
+                remove(member, rest);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Keep only errors of the fields present in the patch. `known` are members
+/// of the patched value, which tell the serialized names of its fields.
+/// Errors of the fields whose names can't be told are kept.
+fn retain_present(
+    errors: &mut ValidationErrors,
+    members: &Map<String, Value>,
+    known: &Map<String, Value>,
+) {
+    errors.errors_mut().retain(|field, kind| {
+        let Some(name) = serialized_name(field, known) else {
+            return true;
+        };
+        match (members.get(name), kind) {
+            (None, _) => false,
+            (Some(Value::Object(members)), ValidationErrorsKind::Struct(errors)) => {
+                let known = known.get(name).and_then(Value::as_object);
+                retain_present(errors, members, known.unwrap_or(&Map::new()));
+                !errors.is_empty()
+            }
+            _ => true,
+        }
+    });
+}
+
+/// Name of the `field` among `known` members, renamed by one of serde's
+/// `rename_all` conventions if it's not there as is.
+fn serialized_name<'a>(field: &str, known: &'a Map<String, Value>) -> Option<&'a str> {
+    if let Some((name, _)) = known.get_key_value(field) {
+        return Some(name);
+    }
+    let words: Vec<&str> = field.split('_').filter(|word| !word.is_empty()).collect();
+    let capitalized: Vec<String> = words
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+    let pascal = capitalized.concat();
+    let camel = match words.first() {
+        Some(first) => format!("{first}{}", capitalized[1..].concat()),
+        None => String::new(),
+    };
+    let candidates = [
+        field.to_uppercase(),
+        pascal,
+        camel,
+        words.join("-"),
+        words.join("-").to_uppercase(),
+    ];
+    let mut names = candidates
+        .iter()
+        .filter_map(|candidate| known.get_key_value(candidate.as_str()))
+        .map(|(name, _)| name.as_str());
+    let name = names.next()?;
+    names.all(|other| other == name).then_some(name)
+}
+
+/// Merge `patch` into `target` as described in RFC 7386.
+fn merge(target: &mut Value, patch: &Value) {
+    let Value::Object(members) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+    if let Value::Object(target) = target {
+        for (name, value) in members {
+            if value.is_null() {
+                target.remove(name);
+            } else {
+                merge(target.entry(name.as_str()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for MergePatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.raw.fmt(f)
+    }
+}
+
+impl<T> FromRequest for MergePatch<T>
+where
+    T: Serialize + DeserializeOwned + Validate + Default + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let (limit, unknown_fields, forbidden_fields) = req
+            .app_data::<JsonConfig>()
+            .map(|c| (c.limit, c.unknown_fields, c.forbidden_fields.clone()))
+            .unwrap_or((32768, UnknownFields::Ignore, Vec::new()));
+        let is_merge_patch = req
+            .mime_type()
+            .ok()
+            .flatten()
+            .is_some_and(|mime| mime.essence_str() == "application/merge-patch+json");
+        let payload = payload.take();
+        let req = req.clone();
+
+        async move {
+            let result = async {
+                if !is_merge_patch {
+                    return Err(JsonPayloadError::ContentType.into());
+                }
+                let body = read_body(req.clone(), payload, limit).await?;
+                let raw: Value = serde_json::from_slice(&body)?;
+                check_value(&raw, &forbidden_fields)?;
+                check::<T>(&raw, unknown_fields, &req)?;
+                Ok::<_, Error>(MergePatch {
+                    raw,
+                    resource: PhantomData,
+                })
+            }
+            .await;
+            result.map_err(|e| {
+                log::debug!(
+                    "Failed to deserialize merge patch from payload. \
+                     Request path: {}",
+                    req.path()
+                );
+                match req
+                    .app_data::<JsonConfig>()
+                    .and_then(|c| c.ehandler.as_ref())
+                {
+                    Some(err) => (*err)(e, &req),
+                    None => e.into(),
+                }
+            })
+        }
+        .boxed_local()
+    }
+}
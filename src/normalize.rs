@@ -0,0 +1,181 @@
+//! Normalization before validation.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::Validate;
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Fix up user input before validation, e.g. trim and lowercase emails.
+///
+/// [`Normalized`](../struct.Normalized.html) extractor runs it between
+/// deserialization and validation. Helpers for common string rules are
+/// available in this module.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web_validator::normalize::{self, Normalize};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct SignUp {
+///     #[validate(email)]
+///     email: String,
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// impl Normalize for SignUp {
+///     fn normalize(&mut self) {
+///         normalize::trim(&mut self.email);
+///         normalize::lowercase(&mut self.email);
+///         normalize::collapse_whitespace(&mut self.name);
+///     }
+/// }
+/// ```
+pub trait Normalize {
+    /// Normalize the value in place.
+    fn normalize(&mut self);
+}
+
+impl<T: Normalize> Normalize for Vec<T> {
+    fn normalize(&mut self) {
+        self.iter_mut().for_each(Normalize::normalize);
+    }
+}
+
+impl<T: Normalize> Normalize for Option<T> {
+    fn normalize(&mut self) {
+        if let Some(value) = self {
+            value.normalize();
+        }
+    }
+}
+
+/// Remove leading and trailing whitespace.
+pub fn trim(value: &mut String) {
+    let trimmed = value.trim();
+    if trimmed.len() != value.len() {
+        *value = trimmed.to_owned();
+    }
+}
+
+/// Convert to lowercase.
+pub fn lowercase(value: &mut String) {
+    *value = value.to_lowercase();
+}
+
+/// Trim and replace every run of whitespace with a single space.
+pub fn collapse_whitespace(value: &mut String) {
+    *value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+}
+
+/// Convert to Unicode Normalization Form C.
+#[cfg(feature = "unicode")]
+pub fn nfc(value: &mut String) {
+    use unicode_normalization::UnicodeNormalization;
+
+    *value = value.nfc().collect();
+}
+
+/// Extract typed information with any of the crate's extractors, normalize
+/// it with [`Normalize`](normalize/trait.Normalize.html) and validate it.
+///
+/// Errors are reported through the inner extractor's configuration, e.g.
+/// [**JsonConfig**](struct.JsonConfig.html) for `Normalized<Json<T>>`.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::normalize::{self, Normalize};
+/// use actix_web_validator::{Json, Normalized};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct SignUp {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// impl Normalize for SignUp {
+///     fn normalize(&mut self) {
+///         normalize::trim(&mut self.email);
+///         normalize::lowercase(&mut self.email);
+///     }
+/// }
+///
+/// /// `email` is trimmed and lowercased here
+/// async fn index(sign_up: Normalized<Json<SignUp>>) -> String {
+///     format!("Welcome {}!", sign_up.email)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/sign-up").route(web::post().to(index)));
+/// }
+/// ```
+pub struct Normalized<E: Extractor>(pub E::Inner);
+
+impl<E: Extractor> Normalized<E> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> E::Inner {
+        self.0
+    }
+}
+
+impl<E: Extractor> AsRef<E::Inner> for Normalized<E> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E: Extractor> Deref for Normalized<E> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E> fmt::Debug for Normalized<E>
+where
+    E: Extractor,
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E> FromRequest for Normalized<E>
+where
+    E: Extractor,
+    E::Future: 'static,
+    E::Inner: Normalize + Validate,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| {
+                res.and_then(|mut value| {
+                    value.normalize();
+                    value.validate()?;
+                    Ok::<_, Error>(Normalized(value))
+                })
+                .map_err(|e| E::error(e, &req))
+            })
+            .boxed_local()
+    }
+}
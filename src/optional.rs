@@ -0,0 +1,122 @@
+//! Extractors accepting absent payload.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{ok, FutureExt, LocalBoxFuture};
+use validator::Validate;
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+use crate::{Form, Json, QsQuery, Query};
+
+/// Extract typed information from the request if it is present.
+///
+/// Unlike `Option<Json<T>>`, which turns every error into `None`, this
+/// extractor yields `None` only when there is nothing to extract: no body
+/// (or an empty one) for [`Json`](struct.Json.html) and
+/// [`Form`](struct.Form.html), an empty query string for
+/// [`Query`](struct.Query.html) and [`QsQuery`](struct.QsQuery.html).
+/// A body is absent when `Content-Length` is zero or, for HTTP/1 requests,
+/// when neither `Content-Length` nor `Transfer-Encoding` is set.
+/// Anything present is deserialized and validated as usual, and errors are
+/// reported through the inner extractor's configuration.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::OptionalJson;
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Settings {
+///     #[validate(range(min = 1, max = 100))]
+///     page_size: u8,
+/// }
+///
+/// async fn index(settings: OptionalJson<Settings>) -> String {
+///     match settings.into_inner() {
+///         Some(settings) => format!("Page size is {}", settings.page_size),
+///         None => "Default page size".to_owned(),
+///     }
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/settings").route(web::post().to(index)));
+/// }
+/// ```
+pub struct Optional<E: Extractor>(pub Option<E::Inner>);
+
+/// [`Json`](struct.Json.html) extractor accepting an empty body.
+pub type OptionalJson<T> = Optional<Json<T>>;
+
+/// [`Form`](struct.Form.html) extractor accepting an empty body.
+pub type OptionalForm<T> = Optional<Form<T>>;
+
+/// [`Query`](struct.Query.html) extractor accepting an empty query string.
+pub type OptionalQuery<T> = Optional<Query<T>>;
+
+/// [`QsQuery`](struct.QsQuery.html) extractor accepting an empty query string.
+pub type OptionalQsQuery<T> = Optional<QsQuery<T>>;
+
+impl<E: Extractor> Optional<E> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> Option<E::Inner> {
+        self.0
+    }
+}
+
+impl<E: Extractor> AsRef<Option<E::Inner>> for Optional<E> {
+    fn as_ref(&self) -> &Option<E::Inner> {
+        &self.0
+    }
+}
+
+impl<E: Extractor> Deref for Optional<E> {
+    type Target = Option<E::Inner>;
+
+    fn deref(&self) -> &Option<E::Inner> {
+        &self.0
+    }
+}
+
+impl<E> fmt::Debug for Optional<E>
+where
+    E: Extractor,
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E> FromRequest for Optional<E>
+where
+    E: Extractor + 'static,
+    E::Future: 'static,
+    E::Inner: Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        if E::is_absent(req, payload) {
+            return ok(Optional(None)).boxed_local();
+        }
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| {
+                res.and_then(|value| {
+                    value.validate()?;
+                    Ok::<_, Error>(Optional(Some(value)))
+                })
+                .map_err(|e| E::error(e, &req))
+            })
+            .boxed_local()
+    }
+}
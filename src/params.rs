@@ -0,0 +1,327 @@
+//! Params extractor.
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use actix_router::Quoter;
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+use serde::de::{self, DeserializeOwned};
+use validator::Validate;
+
+use crate::body::read_body;
+use crate::de::{from_pairs, Value};
+use crate::error::{DeserializeErrors, Error};
+use crate::forbidden_fields::check_value;
+use crate::unknown_fields::{Segment, Tracked, UnknownFields};
+
+/// Extract typed information from the request's path, query and payload
+/// into a single value and validate it.
+///
+/// Every field declares the part of the request it comes from with a
+/// `path.`, `query.` or `body.` prefix of its serde name. Path and query
+/// parameters are parsed from strings, the body must be a JSON object.
+/// Since all parts end up in one struct, rules spanning several of them
+/// are regular struct-level validators.
+///
+/// [**ParamsConfig**](struct.ParamsConfig.html) allows to configure extraction
+/// process.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::Params;
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationError};
+///
+/// #[derive(Deserialize, Validate)]
+/// #[validate(schema(function = "validate_ids"))]
+/// struct UpdateUser {
+///     #[serde(rename = "path.id")]
+///     path_id: u64,
+///     #[serde(rename = "query.dry_run", default)]
+///     dry_run: bool,
+///     #[serde(rename = "body.id")]
+///     id: u64,
+///     #[serde(rename = "body.name")]
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// fn validate_ids(user: &UpdateUser) -> Result<(), ValidationError> {
+///     if user.path_id != user.id {
+///         return Err(ValidationError::new("id_mismatch"));
+///     }
+///     Ok(())
+/// }
+///
+/// /// deserialize `UpdateUser` from request's path, query and body
+/// async fn index(user: Params<UpdateUser>) -> String {
+///     format!("Update user {} to {}", user.id, user.name)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///        web::resource("/users/{id}").route(
+///            web::put().to(index))
+///     );
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Params<T>(pub T);
+
+impl<T> Params<T> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Params<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for Params<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Params<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Params<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> FromRequest for Params<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req2 = req.clone();
+        let (limit, error_handler, unknown_fields, forbidden_fields) = req
+            .app_data::<ParamsConfig>()
+            .map(|c| {
+                (
+                    c.limit,
+                    c.ehandler.clone(),
+                    c.unknown_fields,
+                    c.forbidden_fields.clone(),
+                )
+            })
+            .unwrap_or((32768, None, UnknownFields::Ignore, Vec::new()));
+        let payload = payload.take();
+        let req = req.clone();
+
+        async move {
+            let mut pairs = path_pairs(&req2, "path.");
+            pairs.extend(query_pairs(&req2)?);
+            let body = read_body(req2.clone(), payload, limit).await?;
+            let mut fields = serde_json::Map::new();
+            if !body.is_empty() {
+                if !is_json(&req2) {
+                    return Err(Error::ContentType);
+                }
+                fields = serde_json::from_slice(&body)?;
+            }
+            if !forbidden_fields.is_empty() {
+                check_value(&sources(&pairs, &fields), &forbidden_fields)?;
+            }
+            pairs.extend(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (format!("body.{key}"), Value::from_json(value))),
+            );
+            let value: T = from_pairs::<Tracked<T>>(pairs.clone(), false)
+                .map_err(|e| {
+                    e.into_error(|e| {
+                        Error::from(DeserializeErrors::DeserializeParams(de::Error::custom(e)))
+                    })
+                })?
+                .probe(unknown_fields, false, || Some(Value::Map(pairs)))
+                .retain_ignored(
+                    |path| !matches!(path, [Segment::Key(key), ..] if key.starts_with("path.")),
+                )
+                .check(unknown_fields, &req2)?;
+            value.validate()?;
+            Ok(Params(value))
+        }
+        .map(move |res| match res {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                log::debug!(
+                    "Failed during Params extractor validation. \
+                     Request path: {}",
+                    req.path()
+                );
+                if let Some(error_handler) = error_handler {
+                    Err((error_handler)(e, &req))
+                } else {
+                    Err(e.into())
+                }
+            }
+        })
+        .boxed_local()
+    }
+}
+
+/// Path and query parameters and the body as a single object, e.g.
+/// `{"path": {...}, "query": {...}, "body": {...}}`.
+fn sources(
+    pairs: &[(String, Value)],
+    body: &serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Value {
+    let mut sources = serde_json::Map::new();
+    for (name, value) in pairs {
+        if let (Some((source, key)), Value::Str(value)) = (name.split_once('.'), value) {
+            let source = sources
+                .entry(source)
+                .or_insert_with(|| serde_json::Value::Object(Default::default()));
+            if let serde_json::Value::Object(params) = source {
+                params.insert(key.to_owned(), serde_json::Value::String(value.clone()));
+            }
+        }
+    }
+    sources.insert("body".to_owned(), serde_json::Value::Object(body.clone()));
+    serde_json::Value::Object(sources)
+}
+
+/// Decoded path parameters, with keys prefixed by `prefix`.
+pub(crate) fn path_pairs(req: &HttpRequest, prefix: &str) -> Vec<(String, Value)> {
+    let quoter = Quoter::new(b"", b"");
+    req.match_info()
+        .iter()
+        .map(|(key, value)| {
+            let value = quoter
+                .requote(value.as_bytes())
+                .map(|value| String::from_utf8_lossy(&value).into_owned())
+                .unwrap_or_else(|| value.to_owned());
+            (format!("{prefix}{key}"), Value::Str(value))
+        })
+        .collect()
+}
+
+fn query_pairs(req: &HttpRequest) -> Result<Vec<(String, Value)>, Error> {
+    Ok(
+        serde_urlencoded::from_str::<Vec<(String, String)>>(req.query_string())?
+            .into_iter()
+            .map(|(key, value)| (format!("query.{key}"), Value::Str(value)))
+            .collect(),
+    )
+}
+
+fn is_json(req: &HttpRequest) -> bool {
+    matches!(req.mime_type(), Ok(Some(mime)) if mime.subtype() == mime::JSON
+        || mime.suffix() == Some(mime::JSON))
+}
+
+type ErrHandler = Rc<dyn Fn(Error, &HttpRequest) -> actix_web::Error>;
+
+/// Params extractor configuration
+///
+/// ```rust
+/// use actix_web::{error, web, App, FromRequest, HttpResponse};
+/// use serde::Deserialize;
+/// use actix_web_validator::{Params, ParamsConfig};
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Info {
+///     #[serde(rename = "path.id")]
+///     id: u64,
+///     #[serde(rename = "body.username")]
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// /// deserialize `Info` from request's path and body, max payload size is 4kb
+/// async fn index(info: Params<Info>) -> String {
+///     format!("Welcome {}!", info.username)
+/// }
+///
+/// fn main() {
+///     let params_config = ParamsConfig::default()
+///         .limit(4096)
+///         .error_handler(|err, req| {  // <- create custom error response
+///             error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+///         });
+///     let app = App::new().service(
+///         web::resource("/users/{id}")
+///             .app_data(params_config)
+///             .route(web::post().to(index))
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ParamsConfig {
+    limit: usize,
+    ehandler: Option<ErrHandler>,
+    unknown_fields: UnknownFields,
+    forbidden_fields: Vec<String>,
+}
+
+impl ParamsConfig {
+    /// Change max size of payload. By default max size is 32Kb
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set custom error handler
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Error, &HttpRequest) -> actix_web::Error + 'static,
+    {
+        self.ehandler = Some(Rc::new(f));
+        self
+    }
+
+    /// Set policy for query parameters and body fields unknown to the
+    /// deserialized type, e.g. `body.nmae`. Path parameters are set by the
+    /// route, so they are never reported. By default they are ignored.
+    pub fn unknown_fields(mut self, policy: UnknownFields) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Reject request setting any of the listed fields with `forbidden`
+    /// validation error, even if the deserialized type has them. Fields are
+    /// named with the part of the request they come from, e.g. `body.role`
+    /// or `query.admin`, nested ones are separated by dots.
+    pub fn forbidden_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl Default for ParamsConfig {
+    fn default() -> Self {
+        Self {
+            limit: 32768,
+            ehandler: None,
+            unknown_fields: UnknownFields::Ignore,
+            forbidden_fields: Vec::new(),
+        }
+    }
+}
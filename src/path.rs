@@ -8,9 +8,12 @@ use actix_web::dev::Payload;
 use actix_web::{FromRequest, HttpRequest};
 use futures::future::{ready, Ready};
 use serde::de::{Deserialize, DeserializeOwned};
-use validator::Validate;
+use validator::{Validate, ValidationErrors};
 
+use crate::de::{locate, Value};
 use crate::error::{DeserializeErrors, Error};
+use crate::extractor::Extractor;
+use crate::params::path_pairs;
 
 /// Extract typed information from the request's path.
 ///
@@ -53,6 +56,16 @@ impl<T> Path<T> {
     pub fn into_inner(self) -> T {
         self.inner
     }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded,
+    /// so the value stays valid.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Validate + Clone,
+        F: FnOnce(&mut T),
+    {
+        crate::validated::modify(&mut self.inner, f)
+    }
 }
 
 impl<T> AsRef<T> for Path<T> {
@@ -120,37 +133,53 @@ where
     type Future = Ready<Result<Self, Self::Error>>;
 
     #[inline]
-    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
-        let error_handler = req
-            .app_data::<PathConfig>()
-            .map(|c| c.ehandler.clone())
-            .unwrap_or(None);
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
         ready(
-            Deserialize::deserialize(PathDeserializer::new(req.match_info()))
-                .map_err(|error| Error::Deserialize(DeserializeErrors::DeserializePath(error)))
-                .and_then(|value: T| {
-                    value
-                        .validate()
-                        .map(move |_| value)
-                        .map_err(Error::Validate)
-                })
+            <Self as Extractor>::extract(req, payload)
+                .into_inner()
+                .and_then(|value: T| value.validate().map(move |_| value).map_err(Error::from))
                 .map(|inner| Path { inner })
-                .map_err(move |e| {
-                    log::debug!(
-                        "Failed during Path extractor deserialization. \
-                         Request path: {:?}",
-                        req.path()
-                    );
-                    if let Some(error_handler) = error_handler {
-                        (error_handler)(e, req)
-                    } else {
-                        actix_web::error::ErrorNotFound(e)
-                    }
-                }),
+                .map_err(move |e| Self::error(e, req)),
         )
     }
 }
 
+impl<T> Extractor for Path<T>
+where
+    T: DeserializeOwned,
+{
+    type Inner = T;
+    type Future = Ready<Result<T, Error>>;
+
+    #[inline]
+    fn extract(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            Deserialize::deserialize(PathDeserializer::new(req.match_info())).map_err(|error| {
+                locate::<T, _>(
+                    DeserializeErrors::DeserializePath(error).into(),
+                    false,
+                    || Some(Value::Map(path_pairs(req, ""))),
+                )
+            }),
+        )
+    }
+
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error {
+        log::debug!(
+            "Failed during Path extractor deserialization. \
+             Request path: {:?}",
+            req.path()
+        );
+        match req
+            .app_data::<PathConfig>()
+            .and_then(|c| c.ehandler.as_ref())
+        {
+            Some(error_handler) => (error_handler)(error, req),
+            None => actix_web::error::ErrorNotFound(error),
+        }
+    }
+}
+
 type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
 
 /// Path extractor configuration
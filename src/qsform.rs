@@ -0,0 +1,285 @@
+//! Form extractor (serde_qs based).
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use actix_web::dev::Payload;
+use actix_web::error::UrlencodedError;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use serde::de::DeserializeOwned;
+use serde_qs::Config as QsConfig;
+use validator::{Validate, ValidationErrors};
+
+use crate::body::read_body;
+use crate::de::{locate, Value};
+use crate::error::Error;
+use crate::extractor::Extractor;
+use crate::forbidden_fields::check_value;
+use crate::unknown_fields::{Tracked, UnknownFields};
+
+type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
+
+/// Form extractor configuration (serde_qs based).
+///
+/// ```rust
+/// use actix_web::{error, web, App, HttpResponse};
+/// use actix_web_validator::{QsForm, QsFormConfig};
+/// use serde::Deserialize;
+/// use serde_qs::Config as QsConfig;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Info {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// /// deserialize `Info` from request's body, max payload size is 4kb
+/// async fn index(info: QsForm<Info>) -> String {
+///     format!("Welcome {}!", info.username)
+/// }
+///
+/// fn main() {
+///     let qs_form_config = QsFormConfig::default()
+///         .limit(4096)
+///         .error_handler(|err, req| {  // <- create custom error response
+///             error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+///         })
+///         .qs_config(QsConfig::new(2, false));
+///     let app = App::new().service(
+///         web::resource("/index.html")
+///             .app_data(qs_form_config)
+///             .route(web::post().to(index))
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct QsFormConfig {
+    limit: usize,
+    ehandler: Option<ErrHandler>,
+    qs_config: QsConfig,
+    unknown_fields: UnknownFields,
+    forbidden_fields: Vec<String>,
+}
+
+impl QsFormConfig {
+    /// Change max size of payload. By default max size is 16Kb
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set custom error handler
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        self.ehandler = Some(Arc::new(f));
+        self
+    }
+
+    /// Set custom serialization parameters. By default brackets may be
+    /// percent-encoded, as browsers submit them.
+    pub fn qs_config(mut self, config: QsConfig) -> Self {
+        self.qs_config = config;
+        self
+    }
+
+    /// Set policy for fields unknown to the deserialized type.
+    /// By default they are ignored.
+    pub fn unknown_fields(mut self, policy: UnknownFields) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Reject payload setting any of the listed fields with `forbidden`
+    /// validation error, even if the deserialized type has them. Nested
+    /// fields are separated by dots, e.g. `user.role`.
+    pub fn forbidden_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl Default for QsFormConfig {
+    fn default() -> Self {
+        Self {
+            limit: 16_384,
+            ehandler: None,
+            qs_config: QsConfig::new(5, false),
+            unknown_fields: UnknownFields::Ignore,
+            forbidden_fields: Vec::new(),
+        }
+    }
+}
+
+/// Extract and validate typed information from the request's
+/// `application/x-www-form-urlencoded` body (serde_qs based).
+///
+/// Unlike [`Form`](struct.Form.html), it supports nested structures,
+/// e.g. `user[address][city]=Moscow`.
+/// [`QsFormConfig`] allows to configure extraction process.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::QsForm;
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Address {
+///     #[validate(length(min = 1))]
+///     city: String,
+/// }
+///
+/// #[derive(Deserialize, Validate)]
+/// struct User {
+///     #[validate(nested)]
+///     address: Address,
+/// }
+///
+/// /// deserialize `User` from `address[city]=Moscow` body
+/// async fn index(user: QsForm<User>) -> String {
+///     format!("Welcome to {}!", user.address.city)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/index.html").route(web::post().to(index)));
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct QsForm<T>(pub T);
+
+impl<T> AsRef<T> for QsForm<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for QsForm<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for QsForm<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for QsForm<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> QsForm<T> {
+    /// Deconstruct to an inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded,
+    /// so the value stays valid.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Validate + Clone,
+        F: FnOnce(&mut T),
+    {
+        crate::validated::modify(&mut self.0, f)
+    }
+}
+
+impl<T> FromRequest for QsForm<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req2 = req.clone();
+        <Self as Extractor>::extract(req, payload)
+            .map(|res| match res {
+                Ok(data) => data.validate().map(|_| QsForm(data)).map_err(Error::from),
+                Err(e) => Err(e),
+            })
+            .map(move |res| res.map_err(|e| Self::error(e, &req2)))
+            .boxed_local()
+    }
+}
+
+impl<T> Extractor for QsForm<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    type Inner = T;
+    type Future = LocalBoxFuture<'static, Result<T, Error>>;
+
+    fn extract(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let config = req.app_data::<QsFormConfig>().cloned().unwrap_or_default();
+        let is_form = req
+            .content_type()
+            .eq_ignore_ascii_case(mime::APPLICATION_WWW_FORM_URLENCODED.essence_str());
+        let payload = payload.take();
+        let req = req.clone();
+
+        async move {
+            if !is_form {
+                return Err(UrlencodedError::ContentType.into());
+            }
+            let body = read_body(req.clone(), payload, config.limit).await?;
+            if !config.forbidden_fields.is_empty() {
+                let tree = config
+                    .qs_config
+                    .deserialize_bytes::<serde_json::Map<String, serde_json::Value>>(&body)?;
+                check_value(&serde_json::Value::Object(tree), &config.forbidden_fields)?;
+            }
+            let tree = || {
+                config
+                    .qs_config
+                    .deserialize_bytes(&body)
+                    .ok()
+                    .map(|tree| Value::from_tree(serde_json::Value::Object(tree)))
+            };
+            config
+                .qs_config
+                .deserialize_bytes::<Tracked<T>>(&body)
+                .map_err(|e| locate::<T, _>(e.into(), false, tree))
+                .map(|value| value.probe(config.unknown_fields, false, tree))
+                .and_then(|value| value.check(config.unknown_fields, &req))
+        }
+        .boxed_local()
+    }
+
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error {
+        log::debug!(
+            "Failed to deserialize QsForm from payload. \
+             Request path: {}",
+            req.path()
+        );
+        match req
+            .app_data::<QsFormConfig>()
+            .and_then(|c| c.ehandler.as_ref())
+        {
+            Some(error_handler) => (error_handler)(error, req),
+            None => error.into(),
+        }
+    }
+
+    fn is_absent(req: &HttpRequest, payload: &Payload) -> bool {
+        crate::body::is_empty(req, payload)
+    }
+}
@@ -1,14 +1,18 @@
 //! Query extractor (serde_qs based).
+use crate::de::{from_pairs, locate, Value};
 use crate::error::Error;
+use crate::extractor::Extractor;
+use crate::forbidden_fields::check_value;
+use crate::unknown_fields::{Tracked, UnknownFields};
 use std::ops::Deref;
 use std::sync::Arc;
 use std::{fmt, ops};
 
 use actix_web::{FromRequest, HttpRequest};
-use futures::future::{err, ok, Ready};
+use futures::future::{err, ok, ready, Ready};
 use serde::de;
 use serde_qs::Config as QsConfig;
-use validator::Validate;
+use validator::{Validate, ValidationErrors};
 
 type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
 
@@ -47,6 +51,9 @@ type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + S
 pub struct QsQueryConfig {
     ehandler: Option<ErrHandler>,
     qs_config: QsConfig,
+    unknown_fields: UnknownFields,
+    lenient: bool,
+    forbidden_fields: Vec<String>,
 }
 
 impl QsQueryConfig {
@@ -64,6 +71,35 @@ impl QsQueryConfig {
         self.qs_config = config;
         self
     }
+
+    /// Set policy for fields unknown to the deserialized type.
+    /// By default they are ignored.
+    pub fn unknown_fields(mut self, policy: UnknownFields) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Coerce values to numbers and booleans when their type is not known
+    /// upfront, e.g. for `#[serde(flatten)]` fields and internally tagged
+    /// enums. Disabled by default.
+    ///
+    /// String fields of such content must not look like numbers or booleans.
+    pub fn lenient(mut self, enabled: bool) -> Self {
+        self.lenient = enabled;
+        self
+    }
+
+    /// Reject query setting any of the listed fields with `forbidden`
+    /// validation error, even if the deserialized type has them. Nested
+    /// fields are separated by dots, e.g. `user.role`.
+    pub fn forbidden_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 /// Extract and validate typed information from the request's query (serde_qs based).
@@ -122,6 +158,8 @@ impl<T> Deref for QsQuery<T> {
     }
 }
 
+/// Deprecated: changes made through `DerefMut` are not validated, use
+/// [`modify`](QsQuery::modify) instead.
 impl<T> ops::DerefMut for QsQuery<T> {
     fn deref_mut(&mut self) -> &mut T {
         &mut self.0
@@ -148,6 +186,16 @@ where
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded,
+    /// so the value stays valid unlike with `DerefMut`.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Validate + Clone,
+        F: FnOnce(&mut T),
+    {
+        crate::validated::modify(&mut self.0, f)
+    }
 }
 
 /// Extract typed information from the request's query.
@@ -195,38 +243,96 @@ where
 
     /// Builds Query struct from request and provides validation mechanism
     #[inline]
-    fn from_request(req: &actix_web::HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
-        let query_config = req.app_data::<QsQueryConfig>();
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        <Self as Extractor>::extract(req, payload)
+            .into_inner()
+            .and_then(|value| value.validate().map(move |_| value).map_err(Error::from))
+            .map_err(move |e| Self::error(e, req))
+            .map(|value| ok(QsQuery(value)))
+            .unwrap_or_else(err)
+    }
+}
 
-        let error_handler = query_config.map(|c| c.ehandler.clone()).unwrap_or(None);
+impl<T> Extractor for QsQuery<T>
+where
+    T: de::DeserializeOwned,
+{
+    type Inner = T;
+    type Future = Ready<Result<T, Error>>;
 
+    #[inline]
+    fn extract(req: &HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
         let default_qsconfig = QsConfig::default();
-        let qsconfig = query_config
-            .map(|c| &c.qs_config)
-            .unwrap_or(&default_qsconfig);
-
-        qsconfig
-            .deserialize_str::<T>(req.query_string())
-            .map_err(Error::from)
-            .and_then(|value| {
-                value
-                    .validate()
-                    .map(move |_| value)
-                    .map_err(Error::Validate)
-            })
-            .map_err(move |e| {
-                log::debug!(
-                    "Failed during Query extractor validation. \
-                     Request path: {:?}",
-                    req.path()
-                );
-                if let Some(error_handler) = error_handler {
-                    (error_handler)(e, req)
-                } else {
-                    e.into()
-                }
-            })
-            .map(|value| ok(QsQuery(value)))
-            .unwrap_or_else(err)
+        let (qsconfig, unknown_fields, lenient) = req
+            .app_data::<QsQueryConfig>()
+            .map(|c| (&c.qs_config, c.unknown_fields, c.lenient))
+            .unwrap_or((&default_qsconfig, UnknownFields::Ignore, false));
+
+        if let Some(config) = req
+            .app_data::<QsQueryConfig>()
+            .filter(|c| !c.forbidden_fields.is_empty())
+        {
+            let checked = qsconfig
+                .deserialize_str::<serde_json::Map<String, serde_json::Value>>(req.query_string())
+                .map_err(Error::from)
+                .and_then(|tree| {
+                    check_value(&serde_json::Value::Object(tree), &config.forbidden_fields)
+                });
+            if let Err(e) = checked {
+                return ready(Err(e));
+            }
+        }
+
+        let tree = || {
+            qsconfig
+                .deserialize_str(req.query_string())
+                .ok()
+                .map(|tree| Value::from_tree(serde_json::Value::Object(tree)))
+        };
+        let result = if lenient {
+            qsconfig
+                .deserialize_str::<serde_json::Map<String, serde_json::Value>>(req.query_string())
+                .map_err(Error::from)
+                .and_then(|tree| {
+                    let pairs = tree
+                        .into_iter()
+                        .map(|(key, value)| (key, Value::from_tree(value)))
+                        .collect();
+                    from_pairs::<Tracked<T>>(pairs, true).map_err(|e| {
+                        e.into_error(|e| Error::from(<serde_qs::Error as de::Error>::custom(e)))
+                    })
+                })
+        } else {
+            qsconfig
+                .deserialize_str::<Tracked<T>>(req.query_string())
+                .map_err(|e| locate::<T, _>(e.into(), false, tree))
+        };
+        ready(
+            result
+                .map(|value| value.probe(unknown_fields, lenient, tree))
+                .and_then(|value| value.check(unknown_fields, req)),
+        )
+    }
+
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error {
+        log::debug!(
+            "Failed during Query extractor validation. \
+             Request path: {:?}",
+            req.path()
+        );
+        match req
+            .app_data::<QsQueryConfig>()
+            .and_then(|c| c.ehandler.as_ref())
+        {
+            Some(error_handler) => (error_handler)(error, req),
+            None => error.into(),
+        }
+    }
+
+    fn is_absent(req: &HttpRequest, _: &actix_web::dev::Payload) -> bool {
+        req.query_string().is_empty()
     }
 }
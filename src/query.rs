@@ -1,13 +1,17 @@
 //! Query extractor.
+use crate::de::{from_pairs, group_pairs, locate, Value};
 use crate::error::Error;
+use crate::extractor::Extractor;
+use crate::forbidden_fields::check_keys;
+use crate::unknown_fields::{Tracked, UnknownFields};
 use std::ops::Deref;
 use std::sync::Arc;
 use std::{fmt, ops};
 
 use actix_web::{FromRequest, HttpRequest};
-use futures::future::{err, ok, Ready};
+use futures::future::{err, ok, ready, Ready};
 use serde::de;
-use validator::Validate;
+use validator::{Validate, ValidationErrors};
 
 type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
 
@@ -47,6 +51,11 @@ type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + S
 #[derive(Clone, Default)]
 pub struct QueryConfig {
     pub ehandler: Option<ErrHandler>,
+    unknown_fields: UnknownFields,
+    multi_value: bool,
+    comma_separated: Vec<String>,
+    lenient: bool,
+    forbidden_fields: Vec<String>,
 }
 
 impl QueryConfig {
@@ -58,6 +67,53 @@ impl QueryConfig {
         self.ehandler = Some(Arc::new(f));
         self
     }
+
+    /// Set policy for fields unknown to the deserialized type.
+    /// By default they are ignored.
+    pub fn unknown_fields(mut self, policy: UnknownFields) -> Self {
+        self.unknown_fields = policy;
+        self
+    }
+
+    /// Collect repeated keys, like `?tag=a&tag=b`, into sequence fields.
+    /// Disabled by default.
+    pub fn multi_value(mut self, enabled: bool) -> Self {
+        self.multi_value = enabled;
+        self
+    }
+
+    /// Split values of listed fields by commas, like `?ids=1,2,3`.
+    /// Enables [`multi_value`](#method.multi_value) as well.
+    pub fn comma_separated<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.comma_separated = fields.into_iter().map(Into::into).collect();
+        self.multi_value = true;
+        self
+    }
+
+    /// Coerce values to numbers and booleans when their type is not known
+    /// upfront, e.g. for `#[serde(flatten)]` fields and internally tagged
+    /// enums. Disabled by default.
+    ///
+    /// String fields of such content must not look like numbers or booleans.
+    pub fn lenient(mut self, enabled: bool) -> Self {
+        self.lenient = enabled;
+        self
+    }
+
+    /// Reject query setting any of the listed parameters with `forbidden`
+    /// validation error, even if the deserialized type has them.
+    pub fn forbidden_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 /// Extract and validate typed information from the request's query.
@@ -116,6 +172,8 @@ impl<T> Deref for Query<T> {
     }
 }
 
+/// Deprecated: changes made through `DerefMut` are not validated, use
+/// [`modify`](Query::modify) instead.
 impl<T> ops::DerefMut for Query<T> {
     fn deref_mut(&mut self) -> &mut T {
         &mut self.0
@@ -142,6 +200,16 @@ where
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded,
+    /// so the value stays valid unlike with `DerefMut`.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Validate + Clone,
+        F: FnOnce(&mut T),
+    {
+        crate::validated::modify(&mut self.0, f)
+    }
 }
 
 /// Extract typed information from the request's query.
@@ -189,33 +257,90 @@ where
 
     /// Builds Query struct from request and provides validation mechanism
     #[inline]
-    fn from_request(req: &actix_web::HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
-        let error_handler = req
-            .app_data::<QueryConfig>()
-            .map(|c| c.ehandler.clone())
-            .unwrap_or(None);
-
-        serde_urlencoded::from_str::<T>(req.query_string())
-            .map_err(Error::from)
-            .and_then(|value| {
-                value
-                    .validate()
-                    .map(move |_| value)
-                    .map_err(Error::Validate)
-            })
-            .map_err(move |e| {
-                log::debug!(
-                    "Failed during Query extractor validation. \
-                     Request path: {:?}",
-                    req.path()
-                );
-                if let Some(error_handler) = error_handler {
-                    (error_handler)(e, req)
-                } else {
-                    e.into()
-                }
-            })
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        <Self as Extractor>::extract(req, payload)
+            .into_inner()
+            .and_then(|value| value.validate().map(move |_| value).map_err(Error::from))
+            .map_err(move |e| Self::error(e, req))
             .map(|value| ok(Query(value)))
             .unwrap_or_else(err)
     }
 }
+
+impl<T> Extractor for Query<T>
+where
+    T: de::DeserializeOwned,
+{
+    type Inner = T;
+    type Future = Ready<Result<T, Error>>;
+
+    #[inline]
+    fn extract(req: &HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
+        let config = req.app_data::<QueryConfig>();
+        let unknown_fields = config.map(|c| c.unknown_fields).unwrap_or_default();
+        let result = match config.filter(|c| {
+            c.multi_value
+                || c.lenient
+                || !c.forbidden_fields.is_empty()
+                || c.unknown_fields != UnknownFields::Ignore
+        }) {
+            Some(config) => {
+                let pairs: Vec<(String, String)> =
+                    match serde_urlencoded::from_str(req.query_string()) {
+                        Ok(pairs) => pairs,
+                        Err(e) => return ready(Err(e.into())),
+                    };
+                let keys = pairs.iter().map(|(key, _)| key.as_str());
+                if let Err(e) = check_keys(keys, &config.forbidden_fields) {
+                    return ready(Err(e));
+                }
+                let pairs = if config.multi_value {
+                    group_pairs(pairs, &config.comma_separated)
+                } else {
+                    pairs.into_iter().map(|(k, v)| (k, Value::Str(v))).collect()
+                };
+                from_pairs::<Tracked<T>>(pairs.clone(), config.lenient)
+                    .map_err(|e| {
+                        e.into_error(|e| {
+                            Error::from(<serde_urlencoded::de::Error as de::Error>::custom(e))
+                        })
+                    })
+                    .map(|value| {
+                        value.probe(unknown_fields, config.lenient, || Some(Value::Map(pairs)))
+                    })
+            }
+            None => serde_urlencoded::from_str::<Tracked<T>>(req.query_string()).map_err(|e| {
+                locate::<T, _>(e.into(), false, || {
+                    let pairs: Vec<(String, String)> =
+                        serde_urlencoded::from_str(req.query_string()).ok()?;
+                    Some(Value::Map(
+                        pairs.into_iter().map(|(k, v)| (k, Value::Str(v))).collect(),
+                    ))
+                })
+            }),
+        };
+        ready(result.and_then(|value| value.check(unknown_fields, req)))
+    }
+
+    fn error(error: Error, req: &HttpRequest) -> actix_web::Error {
+        log::debug!(
+            "Failed during Query extractor validation. \
+             Request path: {:?}",
+            req.path()
+        );
+        match req
+            .app_data::<QueryConfig>()
+            .and_then(|c| c.ehandler.as_ref())
+        {
+            Some(error_handler) => (error_handler)(error, req),
+            None => error.into(),
+        }
+    }
+
+    fn is_absent(req: &HttpRequest, _: &actix_web::dev::Payload) -> bool {
+        req.query_string().is_empty()
+    }
+}
@@ -0,0 +1,208 @@
+//! Scalar types validated at deserialization time.
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use validator::{Validate, ValidateEmail, ValidationError, ValidationErrors};
+
+/// Start of the deserialization error message of a rejected value, followed
+/// by its validation error as JSON.
+const REJECTED: &str = "validation failed: ";
+
+/// Deserialization error carrying validation error of the value, so the
+/// extractor reports it as `Error::Validate` instead of the serde message.
+fn reject<E: de::Error>(error: ValidationError) -> E {
+    let error = serde_json::to_string(&error).unwrap_or_default();
+    E::custom(format_args!("{REJECTED}{error}"))
+}
+
+/// Validation error carried by a deserialization error `message`, which
+/// may be wrapped by the deserializer's own message.
+pub(crate) fn rejection(message: &str) -> Option<ValidationError> {
+    let (_, error) = message.split_once(REJECTED)?;
+    serde_json::Deserializer::from_str(error)
+        .into_iter()
+        .next()?
+        .ok()
+}
+
+fn invalid(code: &'static str, value: &impl Serialize) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.add_param(Cow::from("value"), value);
+    error
+}
+
+macro_rules! scalar_impls {
+    ($name:ident [$(const $param:ident: $bound:ty),*], $inner:ty) => {
+        impl<$(const $param: $bound),*> $name<$($param),*> {
+            /// Validate the value.
+            pub fn new(value: $inner) -> Result<Self, ValidationError> {
+                Self::check(&value)?;
+                Ok(Self(value))
+            }
+
+            /// Deconstruct to an inner value.
+            pub fn into_inner(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl<$(const $param: $bound),*> AsRef<$inner> for $name<$($param),*> {
+            fn as_ref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<$(const $param: $bound),*> Deref for $name<$($param),*> {
+            type Target = $inner;
+
+            fn deref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<$(const $param: $bound),*> fmt::Debug for $name<$($param),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl<$(const $param: $bound),*> fmt::Display for $name<$($param),*> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl<$(const $param: $bound),*> TryFrom<$inner> for $name<$($param),*> {
+            type Error = ValidationError;
+
+            fn try_from(value: $inner) -> Result<Self, ValidationError> {
+                Self::new(value)
+            }
+        }
+
+        impl<'de, $(const $param: $bound),*> Deserialize<'de> for $name<$($param),*> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$inner>::deserialize(deserializer).and_then(|value| Self::new(value).map_err(reject))
+            }
+        }
+
+        /// Value is validated at deserialization time, so validation always succeeds.
+        impl<$(const $param: $bound),*> Validate for $name<$($param),*> {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Integer bounded by `MIN` and `MAX` (both inclusive).
+///
+/// Out of bounds value fails with the `range` validation error.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct BoundedU64<const MIN: u64, const MAX: u64>(u64);
+
+impl<const MIN: u64, const MAX: u64> BoundedU64<MIN, MAX> {
+    fn check(value: &u64) -> Result<(), ValidationError> {
+        if !(MIN..=MAX).contains(value) {
+            let mut error = invalid("range", value);
+            error.add_param(Cow::from("min"), &MIN);
+            error.add_param(Cow::from("max"), &MAX);
+            return Err(error);
+        }
+        Ok(())
+    }
+}
+
+scalar_impls!(BoundedU64[const MIN: u64, const MAX: u64], u64);
+
+/// Non-empty string of at most `MAX` characters.
+///
+/// Empty or too long value fails with the `length` validation error.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct NonEmptyString<const MAX: u64>(String);
+
+impl<const MAX: u64> NonEmptyString<MAX> {
+    fn check(value: &str) -> Result<(), ValidationError> {
+        let length = value.chars().count() as u64;
+        if !(1..=MAX).contains(&length) {
+            let mut error = invalid("length", &value);
+            error.add_param(Cow::from("min"), &1);
+            error.add_param(Cow::from("max"), &MAX);
+            return Err(error);
+        }
+        Ok(())
+    }
+}
+
+scalar_impls!(NonEmptyString[const MAX: u64], String);
+
+/// Email address.
+///
+/// Invalid value fails with the `email` validation error.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    fn check(value: &str) -> Result<(), ValidationError> {
+        if value.validate_email() {
+            Ok(())
+        } else {
+            Err(invalid("email", &value))
+        }
+    }
+}
+
+scalar_impls!(EmailAddress[], String);
+
+/// URL slug: lowercase ASCII letters and digits separated by single hyphens,
+/// e.g. `hello-world-2`.
+///
+/// Invalid value fails with the `slug` validation error.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Slug(String);
+
+impl Slug {
+    fn check(value: &str) -> Result<(), ValidationError> {
+        let is_slug = value.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        });
+        if is_slug {
+            Ok(())
+        } else {
+            Err(invalid("slug", &value))
+        }
+    }
+}
+
+scalar_impls!(Slug[], String);
+
+/// UUID in the hyphenated form, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///
+/// Invalid value fails with the `uuid` validation error.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Uuid(String);
+
+impl Uuid {
+    fn check(value: &str) -> Result<(), ValidationError> {
+        let groups: Vec<_> = value.split('-').map(str::len).collect();
+        let is_uuid =
+            groups == [8, 4, 4, 4, 12] && value.chars().all(|c| c == '-' || c.is_ascii_hexdigit());
+        if is_uuid {
+            Ok(())
+        } else {
+            Err(invalid("uuid", &value))
+        }
+    }
+}
+
+scalar_impls!(Uuid[], String);
@@ -0,0 +1,312 @@
+//! Unknown fields policy.
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::{HttpMessage, HttpRequest};
+use serde::de::{Deserialize, DeserializeOwned, Deserializer};
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use crate::de::{from_value, Trace, Value};
+use crate::error::Error;
+
+/// What to do with fields of the payload which are not known to the
+/// deserialized type.
+///
+/// Unlike `#[serde(deny_unknown_fields)]`, the policy is set at runtime
+/// in extractor's configuration, e.g.
+/// [`JsonConfig::unknown_fields`](struct.JsonConfig.html#method.unknown_fields).
+///
+/// Fields buffered by serde for `#[serde(flatten)]` fields and internally
+/// tagged or untagged enums are found by deserializing the payload once more
+/// for every such field which turns out to be used, so `Warn` and `Reject`
+/// cost more for these types. After 64 such attempts the remaining buffered
+/// fields are treated as unknown, e.g. a flattened map accepts at most 64
+/// entries under these policies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnknownFields {
+    /// Silently ignore unknown fields.
+    #[default]
+    Ignore,
+    /// Log unknown fields and record them in request extensions as
+    /// [`UnknownFieldsWarning`](struct.UnknownFieldsWarning.html), see
+    /// [`unknown_fields_header`](fn.unknown_fields_header.html).
+    Warn,
+    /// Reject the request with `unknown_field` validation error for every
+    /// unknown field.
+    Reject,
+}
+
+/// Paths of the unknown fields found in the request, e.g. `items[0].nmae`.
+///
+/// It is put to request extensions by extractors with
+/// [`UnknownFields::Warn`](enum.UnknownFields.html#variant.Warn) policy.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnknownFieldsWarning(pub Vec<String>);
+
+/// Middleware function adding `X-Unknown-Fields` header with paths from
+/// [`UnknownFieldsWarning`](struct.UnknownFieldsWarning.html) to the response.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{middleware, web, App};
+/// use actix_web_validator::{unknown_fields_header, Json, JsonConfig, UnknownFields};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Info {
+///     #[validate(range(min = 1, max = 100))]
+///     page_size: Option<u8>,
+/// }
+///
+/// async fn index(info: Json<Info>) -> String {
+///     format!("Page size is {:?}", info.page_size)
+/// }
+///
+/// fn main() {
+///     let app = App::new()
+///         .wrap(middleware::from_fn(unknown_fields_header))
+///         .app_data(JsonConfig::default().unknown_fields(UnknownFields::Warn))
+///         .service(web::resource("/index.html").route(web::post().to(index)));
+/// }
+/// ```
+pub async fn unknown_fields_header(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let mut res = next.call(req).await?;
+    let warning = res
+        .request()
+        .extensions()
+        .get::<UnknownFieldsWarning>()
+        .map(|warning| warning.0.join(", "));
+    if let Some(value) = warning.and_then(|w| HeaderValue::from_str(&w).ok()) {
+        res.headers_mut()
+            .insert(HeaderName::from_static("x-unknown-fields"), value);
+    }
+    Ok(res)
+}
+
+/// Number of times the payload is deserialized again to find unknown fields
+/// among the buffered ones.
+const MAX_PROBES: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Deserialized value with paths of the ignored fields.
+pub(crate) struct Tracked<T> {
+    value: T,
+    ignored: Vec<Vec<Segment>>,
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Tracked<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut ignored = Vec::new();
+        let value = serde_ignored::deserialize(deserializer, |path| {
+            let mut segments = Vec::new();
+            collect_segments(&path, &mut segments);
+            ignored.push(segments);
+        })?;
+        Ok(Tracked { value, ignored })
+    }
+}
+
+fn collect_segments(path: &serde_ignored::Path, segments: &mut Vec<Segment>) {
+    use serde_ignored::Path;
+
+    match path {
+        Path::Root => {}
+        Path::Seq { parent, index } => {
+            collect_segments(parent, segments);
+            segments.push(Segment::Index(*index));
+        }
+        Path::Map { parent, key } => {
+            collect_segments(parent, segments);
+            segments.push(Segment::Key(key.clone()));
+        }
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => collect_segments(parent, segments),
+    }
+}
+
+impl<T> Tracked<T> {
+    /// Find unknown fields which serde buffered without telling whether they
+    /// were used, like the ones collected for `#[serde(flatten)]`.
+    ///
+    /// `source` is the payload `T` was deserialized from. Buffered fields are
+    /// replaced by indexed values which only `IgnoredAny` accepts, so all of
+    /// them are unknown when `T` still deserializes, and otherwise the error
+    /// tells which one was used. Fields still undecided after
+    /// [`MAX_PROBES`] attempts are reported as unknown.
+    pub(crate) fn probe<F>(mut self, policy: UnknownFields, lenient: bool, source: F) -> Self
+    where
+        T: DeserializeOwned,
+        F: FnOnce() -> Option<Value>,
+    {
+        if policy == UnknownFields::Ignore {
+            return self;
+        }
+        let Some(source) = source() else {
+            return self;
+        };
+        let trace = Trace::default();
+        if from_value::<T>(source.clone(), lenient, Some(trace.clone())).is_err() {
+            return self;
+        }
+        let mut candidates = trace.buffered();
+        // parents go right before their nested fields
+        candidates.sort();
+        candidates.dedup();
+        candidates.retain(|path| !self.ignored.iter().any(|ignored| path.starts_with(ignored)));
+        let mut probes = 0;
+        while !candidates.is_empty() {
+            // nested fields are probed once their parent is known to be used
+            let mut outermost: Vec<usize> = Vec::new();
+            for (i, path) in candidates.iter().enumerate() {
+                if !outermost
+                    .last()
+                    .is_some_and(|&o| path.starts_with(&candidates[o]))
+                {
+                    outermost.push(i);
+                }
+            }
+            if probes == MAX_PROBES {
+                self.ignored
+                    .extend(outermost.into_iter().map(|i| candidates[i].clone()));
+                break;
+            }
+            probes += 1;
+            let marks: Vec<_> = outermost
+                .iter()
+                .enumerate()
+                .map(|(index, &i)| (candidates[i].as_slice(), index))
+                .collect();
+            let mut probe = source.clone();
+            probe.mark(&marks);
+            let used = match from_value::<T>(probe, lenient, None) {
+                Ok(_) => {
+                    self.ignored
+                        .extend(outermost.into_iter().map(|i| candidates[i].clone()));
+                    break;
+                }
+                Err(e) => e.marker().and_then(|index| outermost.get(index).copied()),
+            };
+            if let Some(used) = used {
+                candidates.remove(used);
+                continue;
+            }
+            // the error doesn't tell which field was used, probe the first one alone
+            if probes == MAX_PROBES {
+                continue;
+            }
+            probes += 1;
+            let first = candidates.remove(outermost[0]);
+            let mut probe = source.clone();
+            probe.mark(&[(first.as_slice(), 0)]);
+            if from_value::<T>(probe, lenient, None).is_ok() {
+                candidates.retain(|path| !path.starts_with(&first));
+                self.ignored.push(first);
+            }
+        }
+        self
+    }
+
+    /// Keep only the ignored fields matching `f`.
+    pub(crate) fn retain_ignored<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&[Segment]) -> bool,
+    {
+        self.ignored.retain(|path| f(path));
+        self
+    }
+
+    /// Apply the policy to the ignored fields.
+    pub(crate) fn check(self, policy: UnknownFields, req: &HttpRequest) -> Result<T, Error> {
+        if self.ignored.is_empty() {
+            return Ok(self.value);
+        }
+        match policy {
+            UnknownFields::Ignore => Ok(self.value),
+            UnknownFields::Warn => {
+                let paths: Vec<_> = self.ignored.iter().map(|path| render(path)).collect();
+                log::warn!(
+                    "Unknown fields in request: {}. Request path: {}",
+                    paths.join(", "),
+                    req.path()
+                );
+                req.extensions_mut()
+                    .get_or_insert_with(UnknownFieldsWarning::default)
+                    .0
+                    .extend(paths);
+                Ok(self.value)
+            }
+            UnknownFields::Reject => {
+                let mut errors = ValidationErrors::new();
+                for path in &self.ignored {
+                    insert_error(&mut errors, path, ValidationError::new("unknown_field"));
+                }
+                Err(errors.into())
+            }
+        }
+    }
+}
+
+fn render(path: &[Segment]) -> String {
+    let mut rendered = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) if rendered.is_empty() => rendered.push_str(key),
+            Segment::Key(key) => {
+                rendered.push('.');
+                rendered.push_str(key);
+            }
+            Segment::Index(index) => rendered.push_str(&format!("[{index}]")),
+        }
+    }
+    rendered
+}
+
+/// Insert `error` at `path`, nesting `ValidationErrors` the way `Validate`
+/// derive does. Sequences on top level and errors of sequence items
+/// themselves are put under empty key.
+pub(crate) fn insert_error(
+    errors: &mut ValidationErrors,
+    path: &[Segment],
+    error: ValidationError,
+) {
+    let (key, rest) = match path {
+        [Segment::Key(key), rest @ ..] => (Cow::Owned(key.clone()), rest),
+        rest => (Cow::Borrowed(""), rest),
+    };
+    let entry = errors.errors_mut().entry(key);
+    match rest {
+        [] => {
+            let kind = entry.or_insert_with(|| ValidationErrorsKind::Field(Vec::new()));
+            if let ValidationErrorsKind::Field(field_errors) = kind {
+                field_errors.push(error);
+            }
+        }
+        [Segment::Index(index), rest @ ..] => {
+            let kind = entry.or_insert_with(|| ValidationErrorsKind::List(BTreeMap::new()));
+            if let ValidationErrorsKind::List(items) = kind {
+                insert_error(items.entry(*index).or_default(), rest, error);
+            }
+        }
+        rest => {
+            let kind = entry.or_insert_with(|| ValidationErrorsKind::Struct(Default::default()));
+            if let ValidationErrorsKind::Struct(nested) = kind {
+                insert_error(nested, rest, error);
+            }
+        }
+    }
+}
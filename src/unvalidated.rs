@@ -0,0 +1,132 @@
+//! Extractor deferring validation to the handler.
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::{Validate, ValidateArgs};
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Extract typed information with any of the crate's extractors, but leave
+/// validation to the handler, e.g. when it needs a database record first.
+///
+/// Deserialization errors are reported as usual. Errors of
+/// [`validate`](#method.validate) and [`validate_with`](#method.validate_with)
+/// go through the inner extractor's configuration too, so their response is
+/// the same as if the extractor rejected the request.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App, Error};
+/// use actix_web_validator::{Json, Unvalidated};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Rename {
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// async fn index(rename: Unvalidated<Json<Rename>>) -> Result<String, Error> {
+///     // load the record to rename...
+///     let rename = rename.validate()?;
+///     Ok(format!("Renamed to {}", rename.name))
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/rename").route(web::post().to(index)));
+/// }
+/// ```
+pub struct Unvalidated<E: Extractor> {
+    value: E::Inner,
+    req: HttpRequest,
+}
+
+impl<E: Extractor> Unvalidated<E> {
+    /// Validate the value with `Validate`.
+    pub fn validate(self) -> Result<E::Inner, actix_web::Error>
+    where
+        E::Inner: Validate,
+    {
+        let result = self.value.validate();
+        self.finish(result)
+    }
+
+    /// Validate the value with `ValidateArgs`, e.g. against a context.
+    pub fn validate_with<'a, A>(self, args: A) -> Result<E::Inner, actix_web::Error>
+    where
+        E::Inner: ValidateArgs<'a, Args = A>,
+    {
+        let result = self.value.validate_with_args(args);
+        self.finish(result)
+    }
+
+    /// Deconstruct to the value without validation.
+    pub fn into_inner(self) -> E::Inner {
+        self.value
+    }
+
+    fn finish(
+        self,
+        result: Result<(), validator::ValidationErrors>,
+    ) -> Result<E::Inner, actix_web::Error> {
+        match result {
+            Ok(()) => Ok(self.value),
+            Err(errors) => Err(E::error(Error::from(errors), &self.req)),
+        }
+    }
+}
+
+impl<E: Extractor> AsRef<E::Inner> for Unvalidated<E> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.value
+    }
+}
+
+impl<E: Extractor> Deref for Unvalidated<E> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.value
+    }
+}
+
+impl<E: Extractor> DerefMut for Unvalidated<E> {
+    fn deref_mut(&mut self) -> &mut E::Inner {
+        &mut self.value
+    }
+}
+
+impl<E: Extractor> fmt::Debug for Unvalidated<E>
+where
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<E> FromRequest for Unvalidated<E>
+where
+    E: Extractor + 'static,
+    E::Future: 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| match res {
+                Ok(value) => Ok(Unvalidated { value, req }),
+                Err(e) => Err(E::error(e, &req)),
+            })
+            .boxed_local()
+    }
+}
@@ -0,0 +1,158 @@
+//! Validation of any extractor's value.
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::Validate;
+
+use crate::error::Error;
+
+type ErrHandler = Arc<dyn Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync>;
+
+/// [`Valid`] extractor configuration.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{error, web, App, HttpResponse};
+/// use actix_web_validator::{Valid, ValidConfig};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Info {
+///     #[validate(length(min = 3))]
+///     username: String,
+/// }
+///
+/// async fn index(info: Valid<web::Json<Info>>) -> String {
+///     format!("Welcome {}!", info.username)
+/// }
+///
+/// fn main() {
+///     let valid_config = ValidConfig::default()
+///         .error_handler(|err, req| {  // <- create custom error response
+///             error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+///         });
+///     let app = App::new().service(
+///         web::resource("/index.html")
+///             .app_data(valid_config)
+///             .route(web::post().to(index))
+///     );
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ValidConfig {
+    ehandler: Option<ErrHandler>,
+}
+
+impl ValidConfig {
+    /// Set custom error handler
+    pub fn error_handler<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Error, &HttpRequest) -> actix_web::Error + Send + Sync + 'static,
+    {
+        self.ehandler = Some(Arc::new(f));
+        self
+    }
+}
+
+/// Run any extractor, e.g. `web::Json` or one from another crate, and
+/// validate the value it dereferences to.
+///
+/// Errors of the inner extractor are reported as `Error::Extract` keeping
+/// their response, validation errors as `Error::Validate`. Both go through
+/// [`ValidConfig`] error handler.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::Valid;
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Search {
+///     #[validate(length(min = 1))]
+///     q: String,
+/// }
+///
+/// async fn index(search: Valid<web::Query<Search>>) -> String {
+///     format!("Searching for {}", search.q)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/search").route(web::get().to(index)));
+/// }
+/// ```
+pub struct Valid<E>(pub E);
+
+impl<E> Valid<E> {
+    /// Deconstruct to the inner extractor.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> AsRef<E> for Valid<E> {
+    fn as_ref(&self) -> &E {
+        &self.0
+    }
+}
+
+impl<E> Deref for Valid<E> {
+    type Target = E;
+
+    fn deref(&self) -> &E {
+        &self.0
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for Valid<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E> FromRequest for Valid<E>
+where
+    E: FromRequest + Deref + 'static,
+    E::Target: Validate,
+    E::Future: 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let extract = E::from_request(req, payload);
+        let req = req.clone();
+        extract
+            .map(move |res| {
+                res.map_err(|e| Error::Extract(e.into()))
+                    .and_then(|value| {
+                        value.validate()?;
+                        Ok(Valid(value))
+                    })
+                    .map_err(|e| {
+                        log::debug!(
+                            "Failed during Valid extractor validation. \
+                             Request path: {:?}",
+                            req.path()
+                        );
+                        match req
+                            .app_data::<ValidConfig>()
+                            .and_then(|c| c.ehandler.as_ref())
+                        {
+                            Some(error_handler) => (error_handler)(e, &req),
+                            None => e.into(),
+                        }
+                    })
+            })
+            .boxed_local()
+    }
+}
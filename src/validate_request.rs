@@ -0,0 +1,149 @@
+//! Request-aware validation.
+use std::fmt;
+use std::ops::Deref;
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use futures::future::{FutureExt, LocalBoxFuture};
+use validator::{Validate, ValidationErrors};
+
+use crate::error::Error;
+use crate::extractor::Extractor;
+
+/// Validation rules which depend on the request, e.g. on the caller's role
+/// set to request extensions by an authentication middleware.
+///
+/// [`RequestValidated`](struct.RequestValidated.html) extractor runs it after
+/// validation with `Validate` succeeds.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{HttpMessage, HttpRequest};
+/// use actix_web_validator::ValidateRequest;
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationError, ValidationErrors};
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Role {
+///     Admin,
+///     User,
+/// }
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Task {
+///     #[validate(range(max = 10))]
+///     priority: u8,
+/// }
+///
+/// impl ValidateRequest for Task {
+///     fn validate_request(&self, req: &HttpRequest) -> Result<(), ValidationErrors> {
+///         let is_admin = req.extensions().get::<Role>() == Some(&Role::Admin);
+///         if self.priority > 5 && !is_admin {
+///             let mut errors = ValidationErrors::new();
+///             errors.add("priority", ValidationError::new("forbidden"));
+///             return Err(errors);
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait ValidateRequest {
+    /// Validate the value against the request.
+    fn validate_request(&self, req: &HttpRequest) -> Result<(), ValidationErrors>;
+}
+
+/// Extract typed information with any of the crate's extractors and validate
+/// it with `Validate` and then [`ValidateRequest`](trait.ValidateRequest.html).
+///
+/// Errors of both steps are reported the same way, through the inner
+/// extractor's configuration, e.g. [**JsonConfig**](struct.JsonConfig.html)
+/// for `RequestValidated<Json<T>>`.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App, HttpRequest};
+/// use actix_web_validator::{Json, RequestValidated, ValidateRequest};
+/// use serde::Deserialize;
+/// use validator::{Validate, ValidationErrors};
+///
+/// #[derive(Deserialize, Validate)]
+/// struct Task {
+///     #[validate(range(max = 10))]
+///     priority: u8,
+/// }
+///
+/// impl ValidateRequest for Task {
+///     fn validate_request(&self, req: &HttpRequest) -> Result<(), ValidationErrors> {
+///         // check the caller's permissions here
+///         Ok(())
+///     }
+/// }
+///
+/// async fn index(task: RequestValidated<Json<Task>>) -> String {
+///     format!("Task with priority {} created", task.priority)
+/// }
+///
+/// fn main() {
+///     let app = App::new().service(
+///         web::resource("/tasks").route(web::post().to(index)));
+/// }
+/// ```
+pub struct RequestValidated<E: Extractor>(pub E::Inner);
+
+impl<E: Extractor> RequestValidated<E> {
+    /// Deconstruct to an inner value
+    pub fn into_inner(self) -> E::Inner {
+        self.0
+    }
+}
+
+impl<E: Extractor> AsRef<E::Inner> for RequestValidated<E> {
+    fn as_ref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E: Extractor> Deref for RequestValidated<E> {
+    type Target = E::Inner;
+
+    fn deref(&self) -> &E::Inner {
+        &self.0
+    }
+}
+
+impl<E> fmt::Debug for RequestValidated<E>
+where
+    E: Extractor,
+    E::Inner: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<E> FromRequest for RequestValidated<E>
+where
+    E: Extractor,
+    E::Future: 'static,
+    E::Inner: Validate + ValidateRequest,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    #[inline]
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        E::extract(&req, payload)
+            .map(move |res| {
+                res.and_then(|value| {
+                    value.validate()?;
+                    value.validate_request(&req)?;
+                    Ok::<_, Error>(RequestValidated(value))
+                })
+                .map_err(|e| E::error(e, &req))
+            })
+            .boxed_local()
+    }
+}
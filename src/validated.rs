@@ -0,0 +1,146 @@
+//! Proof of validation.
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use validator::{Validate, ValidationErrors};
+
+use crate::error::Error;
+use crate::{Form, Json, Path, QsForm, QsQuery, Query};
+
+/// Value which passed validation.
+///
+/// The only way to get it outside of extractors is [`Validated::new`], so
+/// functions requiring `Validated<T>` can rely on the value being valid,
+/// whether it came from a request, a message queue or a command line.
+/// Extractors of this crate convert into it with `TryFrom`, which validates
+/// the value again, since their fields are public. Deserialization validates
+/// the value as well.
+///
+/// ## Example
+///
+/// ```rust
+/// use actix_web::{web, App};
+/// use actix_web_validator::{Json, Validated};
+/// use serde::Deserialize;
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct NewUser {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// fn register(user: Validated<NewUser>) -> String {
+///     format!("Welcome {}!", user.email)
+/// }
+///
+/// async fn index(user: Json<NewUser>) -> actix_web::Result<String> {
+///     Ok(register(user.try_into()?))
+/// }
+///
+/// fn main() {
+///     let user = NewUser { email: "john@example.com".to_owned() };
+///     assert!(Validated::new(user).is_ok());
+///
+///     let app = App::new().service(
+///         web::resource("/users").route(web::post().to(index)));
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Validated<T>(T);
+
+impl<T: Validate> Validated<T> {
+    /// Validate the value.
+    pub fn new(value: T) -> Result<Self, ValidationErrors> {
+        value.validate()?;
+        Ok(Validated(value))
+    }
+
+    /// Mutate the value and validate it again. Invalid changes are discarded.
+    pub fn modify<F>(&mut self, f: F) -> Result<(), ValidationErrors>
+    where
+        T: Clone,
+        F: FnOnce(&mut T),
+    {
+        modify(&mut self.0, f)
+    }
+}
+
+/// Apply `f` to a copy of the value and keep it only if it is still valid.
+pub(crate) fn modify<T, F>(value: &mut T, f: F) -> Result<(), ValidationErrors>
+where
+    T: Validate + Clone,
+    F: FnOnce(&mut T),
+{
+    let mut modified = value.clone();
+    f(&mut modified);
+    modified.validate()?;
+    *value = modified;
+    Ok(())
+}
+
+impl<T> Validated<T> {
+    /// Deconstruct to an inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Validated<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Validated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Validated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Serialize> Serialize for Validated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Validated<T>
+where
+    T: Deserialize<'de> + Validate,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer)
+            .and_then(|value| Validated::new(value).map_err(de::Error::custom))
+    }
+}
+
+macro_rules! from_extractor {
+    ($($extractor:ident),*) => {
+        $(
+            impl<T: Validate> TryFrom<$extractor<T>> for Validated<T> {
+                type Error = Error;
+
+                fn try_from(extractor: $extractor<T>) -> Result<Self, Error> {
+                    Ok(Validated::new(extractor.into_inner())?)
+                }
+            }
+        )*
+    };
+}
+
+from_extractor!(Json, Form, Query, QsQuery, QsForm, Path);
@@ -0,0 +1,111 @@
+use actix_web::{
+    error, http::StatusCode, test, test::call_service, web, App, HttpRequest, HttpResponse,
+};
+use actix_web_validator::{AsyncValidate, AsyncValidated, Form, FormConfig, Json};
+use futures::future::LocalBoxFuture;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+struct Users(Vec<&'static str>);
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+struct SignUp {
+    #[validate(length(min = 3))]
+    username: String,
+}
+
+impl AsyncValidate for SignUp {
+    fn validate_async<'a>(
+        &'a self,
+        req: &'a HttpRequest,
+    ) -> LocalBoxFuture<'a, Result<(), ValidationErrors>> {
+        Box::pin(async move {
+            let users = req.app_data::<Users>().unwrap();
+            if users.0.contains(&self.username.as_str()) {
+                let mut errors = ValidationErrors::new();
+                errors.add("username", ValidationError::new("taken"));
+                return Err(errors);
+            }
+            Ok(())
+        })
+    }
+}
+
+fn sign_up(username: &str) -> SignUp {
+    SignUp {
+        username: username.to_owned(),
+    }
+}
+
+async fn test_handler(sign_up: AsyncValidated<Json<SignUp>>) -> HttpResponse {
+    HttpResponse::Ok().body(sign_up.into_inner().username)
+}
+
+#[actix_web::test]
+async fn test_async_json_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(Users(vec!["admin"]))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(sign_up("john"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "john");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(sign_up("admin"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("username"));
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(sign_up("jo"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_async_form_validation_error() {
+    let form_config = FormConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new()
+            .app_data(Users(vec!["admin"]))
+            .app_data(form_config)
+            .service(web::resource("/test").route(web::post().to(
+                |sign_up: AsyncValidated<Form<SignUp>>| async move {
+                    assert_eq!(sign_up.username, "john");
+                    HttpResponse::Ok().finish()
+                },
+            ))),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_form(sign_up("john"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_form(sign_up("admin"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
@@ -0,0 +1,129 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{error::flatten_errors, Batch, JsonConfig};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, PartialEq, Validate, Serialize, Deserialize)]
+struct Item {
+    #[validate(email)]
+    email: String,
+    #[validate(range(min = 18, max = 28))]
+    age: u8,
+}
+
+async fn test_handler(items: Batch<Item>) -> HttpResponse {
+    items.multi_status_response()
+}
+
+fn items() -> Vec<Item> {
+    vec![
+        Item {
+            email: "first@example.com".to_owned(),
+            age: 24,
+        },
+        Item {
+            email: "invalid".to_owned(),
+            age: 24,
+        },
+        Item {
+            email: "third@example.com".to_owned(),
+            age: 42,
+        },
+    ]
+}
+
+#[actix_web::test]
+async fn test_batch_partial_acceptance() {
+    let app = test::init_service(App::new().service(web::resource("/test").to(
+        |items: Batch<Item>| async move {
+            assert_eq!(items.total(), 3);
+            assert!(!items.is_valid());
+            assert_eq!(items.len(), 1);
+            assert_eq!(items[0].0, 0);
+            assert_eq!(items[0].1.email, "first@example.com");
+            assert_eq!(items.errors().keys().copied().collect::<Vec<_>>(), [1, 2]);
+
+            let errors = items.validation_errors().unwrap();
+            let mut fields = flatten_errors(&errors)
+                .into_iter()
+                .map(|(_, field, _)| field)
+                .collect::<Vec<_>>();
+            fields.sort();
+            assert_eq!(fields, ["[1].email", "[2].age"]);
+            HttpResponse::Ok().finish()
+        },
+    )))
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(items())
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_batch_multi_status_response() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(items())
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::MULTI_STATUS);
+    let body: serde_json::Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body,
+        serde_json::json!([
+            {"index": 0, "status": 200},
+            {"index": 1, "status": 400, "errors": [
+                {"field": "[1].email", "code": "email", "message": null}
+            ]},
+            {"index": 2, "status": 400, "errors": [
+                {"field": "[2].age", "code": "range", "message": null}
+            ]},
+        ])
+    );
+}
+
+#[actix_web::test]
+async fn test_batch_deserialize_error() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(serde_json::json!({"email": "first@example.com", "age": 24}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_batch_error() {
+    let json_config = JsonConfig::default().limit(1).error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new().service(
+            web::resource("/test")
+                .app_data(json_config)
+                .route(web::post().to(test_handler)),
+        ),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(items())
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
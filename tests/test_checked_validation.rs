@@ -0,0 +1,71 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Checked, Json, QsQuery};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Draft {
+    #[validate(length(min = 1))]
+    title: String,
+    #[validate(length(max = 10))]
+    body: String,
+}
+
+async fn save_draft(draft: Checked<Json<Draft>>) -> Result<HttpResponse, actix_web::Error> {
+    // the draft would be stored here, valid or not
+    if let Some(error) = draft.rejection() {
+        assert!(draft.errors().is_some());
+        return Err(error);
+    }
+    let (draft, errors) = draft.into_parts();
+    assert!(errors.is_none());
+    Ok(HttpResponse::Ok().body(draft.title))
+}
+
+#[actix_web::test]
+async fn test_checked_validation() {
+    let app = test::init_service(
+        App::new()
+            .service(web::resource("/drafts").route(web::post().to(save_draft)))
+            .service(
+                web::resource("/preview").to(|draft: Checked<QsQuery<Draft>>| async move {
+                    HttpResponse::Ok().body(format!("{} {}", draft.title, draft.is_valid()))
+                }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/drafts")
+        .set_json(serde_json::json!({"title": "Hello", "body": "World"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Hello");
+
+    // Invalid value still reaches the handler
+    let req = test::TestRequest::with_uri("/preview?title=&body=Hello%20World").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, " false");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/drafts")
+        .set_json(serde_json::json!({"title": "", "body": "Hello World"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("title"));
+    assert!(body.contains("body"));
+
+    let req = test::TestRequest::post()
+        .uri("/drafts")
+        .set_json(serde_json::json!({"title": "Hello"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
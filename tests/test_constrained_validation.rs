@@ -0,0 +1,159 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{
+    Error, Json, JsonConfig, Len, Path, PathConfig, Query, QueryConfig, Range, Tuple,
+};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Page {
+    #[validate(nested)]
+    per_page: Range<u8, 1, 50>,
+}
+
+fn error_body(err: Error) -> actix_web::Error {
+    let body = match &err {
+        Error::Validate(e) => actix_web_validator::error::flatten_errors(e)
+            .iter()
+            .map(|(_, field, err)| format!("{field}: {}", err.code))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => String::new(),
+    };
+    error::InternalError::from_response(err, HttpResponse::BadRequest().body(body)).into()
+}
+
+#[actix_web::test]
+async fn test_constrained_path_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(PathConfig::default().error_handler(|err, _| error_body(err)))
+            .service(web::resource("/items/{id}/{name}").to(
+                |path: Path<Tuple<(Range<u32, 1, 100>, Len<String, 3, 32>)>>| async move {
+                    let (id, name) = path.into_inner().into_inner();
+                    HttpResponse::Ok().body(format!("{} {}", id.into_inner(), name.into_inner()))
+                },
+            )),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::with_uri("/items/42/book").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "42 book");
+
+    // Test 400 status
+    let req = test::TestRequest::with_uri("/items/0/book").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, "0: range");
+
+    let req = test::TestRequest::with_uri("/items/101/ab").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("0: range"));
+    assert!(body.contains("1: length"));
+}
+
+#[actix_web::test]
+async fn test_constrained_json_and_query_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().error_handler(|err, _| error_body(err)))
+            .app_data(QueryConfig::default().error_handler(|err, _| error_body(err)))
+            .service(web::resource("/tags").route(web::post().to(
+                |tags: Json<Len<Vec<String>, 1, 3>>| async move {
+                    HttpResponse::Ok().body(tags.join(","))
+                },
+            )))
+            .service(web::resource("/page").to(|page: Query<Page>| async move {
+                HttpResponse::Ok().body(page.per_page.to_string())
+            })),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/tags")
+        .set_json(["a", "b"])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "a,b");
+
+    let req = test::TestRequest::with_uri("/page?per_page=10").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "10");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/tags")
+        .set_json(["a", "b", "c", "d"])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, ": length");
+
+    let req = test::TestRequest::with_uri("/page?per_page=51").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, "per_page: range");
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Quantities {
+    #[validate(nested)]
+    items: Vec<Range<u32, 1, 10>>,
+}
+
+#[actix_web::test]
+async fn test_constrained_collection_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().error_handler(|err, _| error_body(err)))
+            .service(web::resource("/list").route(web::post().to(
+                |list: Json<Vec<Range<u32, 1, 10>>>| async move {
+                    HttpResponse::Ok().body(list.len().to_string())
+                },
+            )))
+            .service(web::resource("/nested").route(web::post().to(
+                |quantities: Json<Quantities>| async move {
+                    HttpResponse::Ok().body(quantities.items.len().to_string())
+                },
+            ))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/list")
+        .set_json([1, 10])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "2");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/list")
+        .set_json([1, 20])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, "[1]: range");
+
+    let req = test::TestRequest::post()
+        .uri("/nested")
+        .set_json(serde_json::json!({"items": [20, 1, 0]}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        test::read_body(resp).await,
+        "items[0]: range, items[2]: range"
+    );
+}
@@ -0,0 +1,165 @@
+use actix_web::{
+    dev::Service, error, http::StatusCode, test, test::call_service, web, App, HttpMessage,
+    HttpResponse,
+};
+use actix_web_validator::{Json, JsonConfig, Query, WithContext};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+struct AllowedCurrencies(Vec<&'static str>);
+
+struct MaxAmount(u64);
+
+fn validate_currency(currency: &str, allowed: &AllowedCurrencies) -> Result<(), ValidationError> {
+    if !allowed.0.contains(&currency) {
+        return Err(ValidationError::new("currency"));
+    }
+    Ok(())
+}
+
+fn validate_amount(amount: u64, max: &MaxAmount) -> Result<(), ValidationError> {
+    if amount > max.0 {
+        return Err(ValidationError::new("max_amount"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+#[validate(context = AllowedCurrencies)]
+struct Payment {
+    #[validate(custom(function = "validate_currency", use_context))]
+    currency: String,
+    #[validate(range(min = 1))]
+    amount: u64,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[validate(context = MaxAmount)]
+struct Limit {
+    #[validate(custom(function = "validate_amount", use_context))]
+    amount: u64,
+}
+
+async fn test_handler(payment: WithContext<Json<Payment>>) -> HttpResponse {
+    HttpResponse::Ok().body(payment.into_inner().currency)
+}
+
+fn payment(currency: &str, amount: u64) -> Payment {
+    Payment {
+        currency: currency.to_owned(),
+        amount,
+    }
+}
+
+#[actix_web::test]
+async fn test_json_context_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(AllowedCurrencies(vec!["EUR", "USD"]))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(payment("EUR", 42))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "EUR");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(payment("RUB", 42))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(payment("EUR", 0))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_query_context_from_extensions() {
+    let app = test::init_service(
+        App::new()
+            .wrap_fn(|req, srv| {
+                req.extensions_mut().insert(MaxAmount(100));
+                srv.call(req)
+            })
+            .service(
+                web::resource("/test").to(|limit: WithContext<Query<Limit>>| async move {
+                    assert_eq!(limit.amount, 42);
+                    HttpResponse::Ok().finish()
+                }),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::with_uri("/test?amount=42").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::with_uri("/test?amount=142").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_context_from_data() {
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(AllowedCurrencies(vec!["USD"])))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(payment("USD", 42))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_missing_context() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(payment("EUR", 42))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[actix_web::test]
+async fn test_custom_context_validation_error() {
+    let json_config = JsonConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new()
+            .app_data(AllowedCurrencies(vec!["EUR"]))
+            .app_data(json_config)
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(payment("USD", 42))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "csv")]
+use actix_web::{
+    error, http::header::CONTENT_TYPE, http::StatusCode, test, test::call_service, web, App,
+    HttpResponse,
+};
+use actix_web_validator::{BoundedU64, Csv, CsvConfig, EmailAddress, Error};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Contact {
+    #[validate(email)]
+    email: String,
+    #[validate(range(min = 18, max = 28))]
+    age: u8,
+}
+
+async fn test_handler(contacts: Csv<Contact>) -> HttpResponse {
+    HttpResponse::Ok().body(contacts.len().to_string())
+}
+
+fn csv_request(body: &'static str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/test")
+        .insert_header((CONTENT_TYPE, "text/csv"))
+        .set_payload(body)
+}
+
+const INVALID_ROWS: &str = "email,age\nfirst@example.com,24\ninvalid,24\nthird@example.com,42\n";
+
+#[actix_web::test]
+async fn test_csv_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = csv_request("age,email\n24,first@example.com\n18,second@example.com\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "2");
+
+    // Test 400 status
+    let req = csv_request(INVALID_ROWS).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("[1].email"));
+    assert!(!body.contains("[2].age"));
+
+    let req = csv_request("email,age\nfirst@example.com,abc\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .insert_header((CONTENT_TYPE, "application/json"))
+        .set_payload("email,age\nfirst@example.com,24\n")
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_csv_collect_errors() {
+    let app = test::init_service(
+        App::new()
+            .app_data(CsvConfig::default().collect_errors(true))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = csv_request(INVALID_ROWS).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("[1].email"));
+    assert!(body.contains("[2].age"));
+
+    // rows are indexed by their position, not by their line
+    let req = csv_request("email,age\n\"multi\nline\",24\ninvalid,42\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("[0].email"));
+    assert!(body.contains("[1].email"));
+    assert!(body.contains("[1].age"));
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Subscriber {
+    email: EmailAddress,
+    age: BoundedU64<18, 28>,
+}
+
+#[actix_web::test]
+async fn test_csv_scalar_columns() {
+    let app = test::init_service(
+        App::new()
+            .app_data(CsvConfig::default().collect_errors(true))
+            .service(web::resource("/test").route(web::post().to(
+                |subscribers: Csv<Subscriber>| async move {
+                    let subscriber = &subscribers[0];
+                    HttpResponse::Ok().body(format!("{} {}", subscriber.email, *subscriber.age))
+                },
+            ))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = csv_request("email,age\nfirst@example.com,24\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "first@example.com 24");
+
+    // Test 400 status
+    let req = csv_request(INVALID_ROWS).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("[1].email: Validation error: email"));
+    assert!(body.contains("[2].age: Validation error: range"));
+}
+
+#[actix_web::test]
+async fn test_csv_limits() {
+    let app = test::init_service(
+        App::new()
+            .app_data(CsvConfig::default().max_rows(1))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = csv_request("email,age\nfirst@example.com,24\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = csv_request("email,age\nfirst@example.com,24\nsecond@example.com,24\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("row limit of 1"));
+
+    let app = test::init_service(
+        App::new()
+            .app_data(CsvConfig::default().limit(8))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = csv_request("email,age\nfirst@example.com,24\n").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_csv_validation_error() {
+    let csv_config = CsvConfig::default().error_handler(|err, _req| {
+        assert!(matches!(err, Error::Validate(_)));
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new().service(
+            web::resource("/test")
+                .app_data(csv_config)
+                .route(web::post().to(test_handler)),
+        ),
+    )
+    .await;
+
+    let req = csv_request(INVALID_ROWS).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
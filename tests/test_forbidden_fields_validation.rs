@@ -0,0 +1,242 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{
+    Batch, Form, FormConfig, Json, JsonConfig, MergePatch, Params, ParamsConfig, QsQuery,
+    QsQueryConfig, Query, QueryConfig,
+};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct UserUpdate {
+    id: Option<u64>,
+    role: Option<String>,
+    #[validate(length(min = 1))]
+    name: String,
+    #[serde(default)]
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Item {
+    id: Option<u64>,
+    #[validate(length(min = 1))]
+    label: String,
+}
+
+fn render(user: &UserUpdate) -> HttpResponse {
+    HttpResponse::Ok().body(format!(
+        "{:?} {:?} {} {:?}",
+        user.id,
+        user.role,
+        user.name,
+        user.items
+            .iter()
+            .map(|item| (item.id, item.label.as_str()))
+            .collect::<Vec<_>>()
+    ))
+}
+
+#[actix_web::test]
+async fn test_forbidden_fields_validation() {
+    let app = test::init_service(
+        App::new()
+            .service(
+                web::resource("/users")
+                    .app_data(JsonConfig::default().forbidden_fields(["id", "role", "items.id"]))
+                    .route(web::patch().to(|user: Json<UserUpdate>| async move { render(&user) })),
+            )
+            .service(
+                web::resource("/form")
+                    .app_data(FormConfig::default().forbidden_fields(["role"]))
+                    .route(web::post().to(|user: Form<UserUpdate>| async move { render(&user) })),
+            )
+            .service(
+                web::resource("/query")
+                    .app_data(QueryConfig::default().forbidden_fields(["role"]))
+                    .to(|user: Query<UserUpdate>| async move { render(&user) }),
+            )
+            .service(
+                web::resource("/qs")
+                    .app_data(QsQueryConfig::default().forbidden_fields(["items.id"]))
+                    .to(|user: QsQuery<UserUpdate>| async move { render(&user) }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::patch()
+        .uri("/users")
+        .set_json(serde_json::json!({"name": "Alice", "items": [{"label": "a"}]}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        "None None Alice [(None, \"a\")]"
+    );
+
+    // Content type is not required, as without forbidden fields
+    let req = test::TestRequest::patch()
+        .uri("/users")
+        .set_payload(r#"{"name": "Alice"}"#)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([("id", "1"), ("name", "Alice")])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Some(1) None Alice []");
+
+    let req = test::TestRequest::with_uri("/query?name=Alice").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req =
+        test::TestRequest::with_uri("/qs?name=Alice&items[0][label]=a&role=admin").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Test 400 status
+    let req = test::TestRequest::patch()
+        .uri("/users")
+        .set_json(serde_json::json!({"id": 1, "role": "admin", "name": "Alice"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("forbidden"));
+    assert!(body.contains("id"));
+    assert!(body.contains("role"));
+
+    let req = test::TestRequest::patch()
+        .uri("/users")
+        .set_json(serde_json::json!({"name": "Alice", "items": [{"label": "a"}, {"id": 2, "label": "b"}]}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("items[1].id"));
+
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([("name", "Alice"), ("role", "admin")])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("forbidden"));
+
+    let req = test::TestRequest::with_uri("/query?name=Alice&role=admin").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req =
+        test::TestRequest::with_uri("/qs?name=Alice&items[0][label]=a&items[0][id]=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("items[0].id"));
+}
+
+#[derive(Debug, Default, Validate, Serialize, Deserialize)]
+struct Profile {
+    #[validate(length(min = 1))]
+    name: String,
+    role: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct ProfileParams {
+    #[serde(rename = "body.name")]
+    #[validate(length(min = 1))]
+    name: String,
+    #[serde(rename = "body.role")]
+    role: Option<String>,
+}
+
+#[actix_web::test]
+async fn test_forbidden_fields_of_other_extractors() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().forbidden_fields(["role"]))
+            .app_data(ParamsConfig::default().forbidden_fields(["body.role"]))
+            .service(web::resource("/patch").route(web::patch().to(
+                |patch: MergePatch<Profile>| async move { HttpResponse::Ok().json(patch.raw()) },
+            )))
+            .service(web::resource("/batch").route(
+                web::post().to(|batch: Batch<Profile>| async move {
+                    HttpResponse::Ok().body(batch.len().to_string())
+                }),
+            ))
+            .service(web::resource("/params").route(web::post().to(
+                |params: Params<ProfileParams>| async move {
+                    HttpResponse::Ok().body(format!("{} {:?}", params.name, params.role))
+                },
+            ))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::patch()
+        .uri("/patch")
+        .insert_header(("content-type", "application/merge-patch+json"))
+        .set_payload(r#"{"name": "Alice"}"#)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/batch")
+        .set_json(serde_json::json!([{"name": "Alice"}, {"name": "Bob"}]))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "2");
+
+    let req = test::TestRequest::post()
+        .uri("/params")
+        .set_json(serde_json::json!({"name": "Alice"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Alice None");
+
+    // Test 400 status
+    let req = test::TestRequest::patch()
+        .uri("/patch")
+        .insert_header(("content-type", "application/merge-patch+json"))
+        .set_payload(r#"{"name": "Alice", "role": "admin"}"#)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("role: Validation error: forbidden"));
+
+    let req = test::TestRequest::post()
+        .uri("/batch")
+        .set_json(serde_json::json!([{"name": "Alice"}, {"name": "Bob", "role": "admin"}]))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("[1].role"));
+
+    let req = test::TestRequest::post()
+        .uri("/params")
+        .set_json(serde_json::json!({"name": "Alice", "role": "admin"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("body.role"));
+}
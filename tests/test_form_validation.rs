@@ -25,7 +25,7 @@ async fn test_handler(query: Form<FormData>) -> HttpResponse {
 
 #[actix_web::test]
 async fn test_form_validation() {
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new().service(web::resource("/test").route(web::post().to(test_handler))),
     )
     .await;
@@ -38,7 +38,7 @@ async fn test_form_validation() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 
     // Test 400 status
@@ -49,7 +49,7 @@ async fn test_form_validation() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 }
 
@@ -58,7 +58,7 @@ async fn test_custom_form_validation_error() {
     let form_config = FormConfig::default().error_handler(|err, _req| {
         error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
     });
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new().service(
             web::resource("/test")
                 .app_data(form_config)
@@ -74,14 +74,14 @@ async fn test_custom_form_validation_error() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     dbg!(&resp);
     assert_eq!(resp.status(), StatusCode::CONFLICT);
 }
 
 #[actix_web::test]
 async fn test_validated_form_asref_deref() {
-    let mut app = test::init_service(App::new().service(web::resource("/test").to(
+    let app = test::init_service(App::new().service(web::resource("/test").to(
         |payload: Form<FormData>| async move {
             assert_eq!(payload.age, 24);
             let reference = FormData {
@@ -101,12 +101,12 @@ async fn test_validated_form_asref_deref() {
             age: 24,
         })
         .to_request();
-    call_service(&mut app, req).await;
+    call_service(&app, req).await;
 }
 
 #[actix_web::test]
 async fn test_validated_form_into_inner() {
-    let mut app = test::init_service(App::new().service(web::resource("/test").to(
+    let app = test::init_service(App::new().service(web::resource("/test").to(
         |payload: Form<FormData>| async {
             let payload = payload.into_inner();
             assert_eq!(payload.age, 24);
@@ -123,12 +123,12 @@ async fn test_validated_form_into_inner() {
             age: 24,
         })
         .to_request();
-    call_service(&mut app, req).await;
+    call_service(&app, req).await;
 }
 
 #[actix_web::test]
 async fn test_validated_form_limit() {
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new()
             .app_data(FormConfig::default().limit(1))
             .service(web::resource("/test").route(web::post().to(test_handler))),
@@ -142,6 +142,6 @@ async fn test_validated_form_limit() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 }
@@ -0,0 +1,129 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Form, Group, Json, Query, ValidateGroup};
+use serde::Deserialize;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+struct OnCreate;
+
+struct OnUpdate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Article {
+    id: Option<u64>,
+    #[validate(length(min = 1))]
+    title: String,
+}
+
+fn id_error(code: &'static str) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    errors.add("id", ValidationError::new(code));
+    Err(errors)
+}
+
+impl ValidateGroup<OnCreate> for Article {
+    fn validate_group(&self) -> Result<(), ValidationErrors> {
+        self.validate()?;
+        match self.id {
+            Some(_) => id_error("forbidden"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl ValidateGroup<OnUpdate> for Article {
+    fn validate_group(&self) -> Result<(), ValidationErrors> {
+        self.validate()?;
+        match self.id {
+            Some(_) => Ok(()),
+            None => id_error("required"),
+        }
+    }
+}
+
+fn render(article: &Article) -> HttpResponse {
+    HttpResponse::Ok().body(format!("{:?} {}", article.id, article.title))
+}
+
+#[actix_web::test]
+async fn test_group_validation() {
+    let app = test::init_service(
+        App::new()
+            .service(
+                web::resource("/articles")
+                    .route(
+                        web::post()
+                            .to(|a: Group<Json<Article>, OnCreate>| async move { render(&a) }),
+                    )
+                    .route(
+                        web::put()
+                            .to(|a: Group<Json<Article>, OnUpdate>| async move { render(&a) }),
+                    ),
+            )
+            .service(web::resource("/form").route(
+                web::post().to(|a: Group<Form<Article>, OnCreate>| async move { render(&a) }),
+            ))
+            .service(
+                web::resource("/query")
+                    .to(|a: Group<Query<Article>, OnUpdate>| async move { render(&a) }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/articles")
+        .set_json(serde_json::json!({"title": "Hello"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "None Hello");
+
+    let req = test::TestRequest::put()
+        .uri("/articles")
+        .set_json(serde_json::json!({"id": 1, "title": "Hello"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Some(1) Hello");
+
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([("title", "Hello")])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::with_uri("/query?id=2&title=Hello").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/articles")
+        .set_json(serde_json::json!({"id": 1, "title": "Hello"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("forbidden"));
+
+    let req = test::TestRequest::put()
+        .uri("/articles")
+        .set_json(serde_json::json!({"title": "Hello"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("required"));
+
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([("id", "1"), ("title", "Hello")])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/query?id=2&title=").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "json-patch")]
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Error, JsonPatch, JsonPatchConfig};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+struct Profile {
+    #[validate(length(min = 3))]
+    username: String,
+    #[validate(range(min = 18))]
+    age: u8,
+}
+
+async fn test_handler(patch: JsonPatch<Profile>) -> actix_web::Result<HttpResponse> {
+    let profile = Profile {
+        username: "john".to_owned(),
+        age: 42,
+    };
+    let profile = patch.apply_to(&profile)?;
+    Ok(HttpResponse::Ok().json(profile))
+}
+
+fn json_patch(body: &str) -> test::TestRequest {
+    test::TestRequest::patch()
+        .uri("/test")
+        .insert_header(("content-type", "application/json-patch+json"))
+        .set_payload(body.to_owned())
+}
+
+#[actix_web::test]
+async fn test_json_patch_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonPatchConfig::default().allowed_operations(["test", "replace"]))
+            .service(web::resource("/test").route(web::patch().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = json_patch(
+        r#"[
+            {"op": "test", "path": "/username", "value": "john"},
+            {"op": "replace", "path": "/age", "value": 30}
+        ]"#,
+    )
+    .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        r#"{"username":"john","age":30}"#
+    );
+
+    // Test 400 status
+    let req = json_patch(r#"[{"op": "replace", "path": "/age", "value": 10}]"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = json_patch(
+        r#"[
+            {"op": "replace", "path": "/age", "value": 30},
+            {"op": "remove", "path": "/username"}
+        ]"#,
+    )
+    .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("operation 1: operation `remove` is not allowed"));
+
+    let req = json_patch(r#"[{"op": "replace", "path": "age", "value": 30}]"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = json_patch(r#"[{"op": "test", "path": "/username", "value": "jack"}]"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_json_patch_validation_error() {
+    let json_patch_config = JsonPatchConfig::default().error_handler(|err, _req| {
+        assert!(matches!(err, Error::JsonPatch { index: 1, .. }));
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new()
+            .app_data(json_patch_config)
+            .service(web::resource("/test").route(web::patch().to(test_handler))),
+    )
+    .await;
+
+    let req = json_patch(r#"[{"op": "remove", "path": "/age"}]"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = json_patch(
+        r#"[
+            {"op": "add", "path": "/age", "value": 30},
+            {"op": "unknown", "path": "/age"}
+        ]"#,
+    )
+    .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
@@ -0,0 +1,150 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Json, JsonConfig, JsonWithRaw, UnknownFields};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+struct Event {
+    #[validate(length(min = 1))]
+    kind: String,
+}
+
+fn signature_config() -> JsonConfig {
+    JsonConfig::default().verifier(|req, body| {
+        let signature = req
+            .headers()
+            .get("x-signature")
+            .and_then(|s| s.to_str().ok())
+            .and_then(|s| s.parse::<usize>().ok());
+        match signature {
+            Some(signature) if signature == body.len() => Ok(()),
+            _ => Err(error::ErrorUnauthorized("Invalid signature")),
+        }
+    })
+}
+
+fn event(body: &str, signature: usize) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("content-type", "application/json"))
+        .insert_header(("x-signature", signature.to_string()))
+        .set_payload(body.to_owned())
+}
+
+#[actix_web::test]
+async fn test_json_with_raw_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(
+            |event: JsonWithRaw<Event>| async move {
+                let (event, raw) = event.into_parts();
+                HttpResponse::Ok().body(format!("{} {}", event.kind, raw.len()))
+            },
+        ))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = event(r#"{"kind": "push"}"#, 0).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "push 16");
+
+    // Test 400 status
+    let req = event(r#"{"kind": ""}"#, 0).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_json_verifier() {
+    let app = test::init_service(
+        App::new()
+            .app_data(signature_config())
+            .service(web::resource("/raw").route(
+                web::post().to(|event: JsonWithRaw<Event>| async move {
+                    HttpResponse::Ok().body(event.raw().clone())
+                }),
+            ))
+            .service(web::resource("/test").route(
+                web::post().to(|event: Json<Event>| async move {
+                    HttpResponse::Ok().body(event.into_inner().kind)
+                }),
+            )),
+    )
+    .await;
+
+    let req = event(r#"{"kind": "push"}"#, 16).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "push");
+
+    let req = event(r#"{"kind": "push"}"#, 16).uri("/raw").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, r#"{"kind": "push"}"#);
+
+    // Content type is not required, as for `Json` without verifier
+    for uri in ["/test", "/raw"] {
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("x-signature", "16"))
+            .set_payload(r#"{"kind": "push"}"#)
+            .to_request();
+        let resp = call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    let req = event(r#"{"kind": "push"}"#, 3).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let req = event(r#"{"kind": "push"}"#, 3).uri("/raw").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let req = event(r#"{"kind": ""}"#, 12).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_json_with_raw_unknown_fields() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().unknown_fields(UnknownFields::Reject))
+            .service(web::resource("/test").route(web::post().to(
+                |event: JsonWithRaw<Event>| async move {
+                    HttpResponse::Ok().body(event.into_inner().kind)
+                },
+            ))),
+    )
+    .await;
+
+    let req = event(r#"{"kind": "push"}"#, 0).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = event(r#"{"kind": "push", "knid": "pull"}"#, 0).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("knid"));
+}
+
+#[actix_web::test]
+async fn test_custom_json_with_raw_validation_error() {
+    let json_config = JsonConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app =
+        test::init_service(App::new().app_data(json_config).service(
+            web::resource("/test").route(
+                web::post().to(|_: JsonWithRaw<Event>| async { HttpResponse::Ok().finish() }),
+            ),
+        ))
+        .await;
+
+    let req = event(r#"{"kind": ""}"#, 0).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
@@ -18,7 +18,7 @@ async fn test_handler(query: Json<JsonPayload>) -> HttpResponse {
 
 #[actix_web::test]
 async fn test_json_validation() {
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new().service(web::resource("/test").route(web::post().to(test_handler))),
     )
     .await;
@@ -31,7 +31,7 @@ async fn test_json_validation() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 
     let req = test::TestRequest::post()
@@ -41,7 +41,7 @@ async fn test_json_validation() {
             age: 18,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 
     let req = test::TestRequest::post()
@@ -51,7 +51,7 @@ async fn test_json_validation() {
             age: 28,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 
     // Test 400 status
@@ -62,7 +62,7 @@ async fn test_json_validation() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 
     let req = test::TestRequest::post()
@@ -72,7 +72,7 @@ async fn test_json_validation() {
             age: 17,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 
     let req = test::TestRequest::post()
@@ -82,7 +82,7 @@ async fn test_json_validation() {
             age: 29,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 }
 
@@ -91,7 +91,7 @@ async fn test_custom_json_validation_error() {
     let json_config = JsonConfig::default().error_handler(|err, _req| {
         error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
     });
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new().service(
             web::resource("/test")
                 .app_data(json_config)
@@ -107,14 +107,14 @@ async fn test_custom_json_validation_error() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     dbg!(&resp);
     assert_eq!(resp.status(), StatusCode::CONFLICT);
 }
 
 #[actix_web::test]
 async fn test_validated_json_asref_deref() {
-    let mut app = test::init_service(App::new().service(web::resource("/test").to(
+    let app = test::init_service(App::new().service(web::resource("/test").to(
         |payload: Json<JsonPayload>| async move {
             assert_eq!(payload.age, 24);
             let reference = JsonPayload {
@@ -134,12 +134,12 @@ async fn test_validated_json_asref_deref() {
             age: 24,
         })
         .to_request();
-    call_service(&mut app, req).await;
+    call_service(&app, req).await;
 }
 
 #[actix_web::test]
 async fn test_validated_json_into_inner() {
-    let mut app = test::init_service(App::new().service(web::resource("/test").to(
+    let app = test::init_service(App::new().service(web::resource("/test").to(
         |payload: Json<JsonPayload>| async {
             let payload = payload.into_inner();
             assert_eq!(payload.age, 24);
@@ -156,12 +156,12 @@ async fn test_validated_json_into_inner() {
             age: 24,
         })
         .to_request();
-    call_service(&mut app, req).await;
+    call_service(&app, req).await;
 }
 
 #[actix_web::test]
 async fn test_validated_json_limit() {
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new()
             .app_data(JsonConfig::default().limit(1))
             .service(web::resource("/test").route(web::post().to(test_handler))),
@@ -175,6 +175,31 @@ async fn test_validated_json_limit() {
             age: 24,
         })
         .to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 }
+
+#[actix_web::test]
+async fn test_validated_json_responder() {
+    let app = test::init_service(
+        App::new().service(
+            web::resource("/test")
+                .to(|query: web::Query<JsonPayload>| async move { Json(query.into_inner()) }),
+        ),
+    )
+    .await;
+
+    let req = test::TestRequest::with_uri("/test?page_url=https://my_page.com&age=24").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        r#"{"page_url":"https://my_page.com","age":24}"#
+    );
+
+    let req = test::TestRequest::with_uri("/test?page_url=https://my_page.com&age=42").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("age"));
+}
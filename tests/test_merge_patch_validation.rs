@@ -0,0 +1,148 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{JsonConfig, MergePatch};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Default, Validate, Serialize, Deserialize)]
+struct Profile {
+    #[validate(length(min = 3))]
+    username: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(length(max = 20))]
+    nickname: Option<String>,
+}
+
+async fn test_handler(patch: MergePatch<Profile>) -> actix_web::Result<HttpResponse> {
+    let profile = Profile {
+        username: "john".to_owned(),
+        age: 42,
+        nickname: Some("johnny".to_owned()),
+    };
+    let profile = patch.apply_to(&profile)?;
+    Ok(HttpResponse::Ok().json(profile))
+}
+
+fn merge_patch(body: &str) -> test::TestRequest {
+    test::TestRequest::patch()
+        .uri("/test")
+        .insert_header(("content-type", "application/merge-patch+json"))
+        .set_payload(body.to_owned())
+}
+
+#[actix_web::test]
+async fn test_merge_patch_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::patch().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = merge_patch(r#"{"age": 30}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        r#"{"username":"john","age":30,"nickname":"johnny"}"#
+    );
+
+    let req = merge_patch(r#"{"username": "jack", "nickname": null}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        r#"{"username":"jack","age":42,"nickname":null}"#
+    );
+
+    // Test 400 status
+    let req = merge_patch(r#"{"age": 10}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("age: Validation error: range"));
+    assert!(!body.contains("username"));
+
+    let req = merge_patch(r#"{"username": null}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("username: Validation error: required"));
+
+    let req = test::TestRequest::patch()
+        .uri("/test")
+        .insert_header(("content-type", "application/json"))
+        .set_payload(r#"{"age": 30}"#)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_merge_patch_validation_error() {
+    let json_config = JsonConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(App::new().app_data(json_config).service(
+        web::resource("/test").route(web::patch().to(|patch: MergePatch<Profile>| async move {
+            assert_eq!(patch.raw()["age"], 30);
+            HttpResponse::Ok().finish()
+        })),
+    ))
+    .await;
+
+    let req = merge_patch(r#"{"age": 30}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = merge_patch(r#"{"username": "jo"}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
+
+#[derive(Debug, Default, Validate, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Account {
+    #[validate(length(min = 3))]
+    user_name: String,
+    #[validate(range(min = 18))]
+    age_years: u8,
+}
+
+#[actix_web::test]
+async fn test_renamed_fields_merge_patch_validation() {
+    let app = test::init_service(App::new().service(web::resource("/test").route(
+        web::patch().to(|patch: MergePatch<Account>| async move {
+            HttpResponse::Ok().body(patch.raw().to_string())
+        }),
+    )))
+    .await;
+
+    // Test 200 status
+    let req = merge_patch(r#"{"userName": "john"}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = merge_patch(r#"{"ageYears": 30}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Test 400 status
+    let req = merge_patch(r#"{"userName": "x"}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("user_name: Validation error: length"));
+    assert!(!body.contains("age_years"));
+
+    let req = merge_patch(r#"{"ageYears": 7}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("age_years: Validation error: range"));
+    assert!(!body.contains("user_name"));
+}
@@ -0,0 +1,61 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Json, QsQuery, Query, Validated};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate, Deserialize)]
+struct Pagination {
+    #[validate(range(min = 1, max = 100))]
+    per_page: u32,
+}
+
+async fn query_handler(mut query: Query<Pagination>) -> HttpResponse {
+    let doubled = query.modify(|q| q.per_page *= 2);
+    let body = format!("{} {}", doubled.is_ok(), query.per_page);
+    HttpResponse::Ok().body(body)
+}
+
+#[actix_web::test]
+async fn test_query_modify() {
+    let app = test::init_service(
+        App::new()
+            .service(web::resource("/query").to(query_handler))
+            .service(
+                web::resource("/qs").to(|mut query: QsQuery<Pagination>| async move {
+                    let result = query.modify(|q| q.per_page = 0);
+                    HttpResponse::Ok().body(format!("{} {}", result.is_ok(), query.per_page))
+                }),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::with_uri("/query?per_page=30").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "true 60");
+
+    // Invalid change is discarded
+    let req = test::TestRequest::with_uri("/query?per_page=60").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "false 60");
+
+    let req = test::TestRequest::with_uri("/qs?per_page=10").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "false 10");
+}
+
+#[actix_web::test]
+async fn test_json_and_validated_modify() {
+    let mut json = Json(Pagination { per_page: 10 });
+    assert!(json.modify(|p| p.per_page = 20).is_ok());
+    assert_eq!(json.per_page, 20);
+    let errors = json.modify(|p| p.per_page = 500).unwrap_err();
+    assert!(errors.field_errors().contains_key("per_page"));
+    assert_eq!(json.per_page, 20);
+
+    let mut validated = Validated::new(Pagination { per_page: 10 }).unwrap();
+    assert!(validated.modify(|p| p.per_page = 0).is_err());
+    assert_eq!(validated.per_page, 10);
+}
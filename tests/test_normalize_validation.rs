@@ -0,0 +1,96 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::normalize::{self, Normalize};
+use actix_web_validator::{Form, FormConfig, Json, Normalized};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+struct SignUp {
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+impl Normalize for SignUp {
+    fn normalize(&mut self) {
+        normalize::trim(&mut self.email);
+        normalize::lowercase(&mut self.email);
+        normalize::collapse_whitespace(&mut self.name);
+    }
+}
+
+fn sign_up(email: &str, name: &str) -> SignUp {
+    SignUp {
+        email: email.to_owned(),
+        name: name.to_owned(),
+    }
+}
+
+async fn test_handler(sign_up: Normalized<Json<SignUp>>) -> HttpResponse {
+    let sign_up = sign_up.into_inner();
+    HttpResponse::Ok().body(format!("{} {}", sign_up.email, sign_up.name))
+}
+
+#[actix_web::test]
+async fn test_normalized_json_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(sign_up(" John@Example.COM  ", "  John   Smith "))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "john@example.com John Smith");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(sign_up("john@example.com", "   "))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_normalized_form_validation_error() {
+    let form_config = FormConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(App::new().app_data(form_config).service(
+        web::resource("/test").route(web::post().to(
+            |sign_up: Normalized<Form<SignUp>>| async move {
+                assert_eq!(sign_up.email, "john@example.com");
+                HttpResponse::Ok().finish()
+            },
+        )),
+    ))
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_form(sign_up("JOHN@example.com ", "John"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_form(sign_up("john", "John"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
+
+#[cfg(feature = "unicode")]
+#[actix_web::test]
+async fn test_nfc() {
+    let mut value = "Cafe\u{301}".to_owned();
+    normalize::nfc(&mut value);
+    assert_eq!(value, "Caf\u{e9}");
+}
@@ -0,0 +1,106 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{FormConfig, OptionalForm, OptionalJson, OptionalQuery};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+struct Settings {
+    #[validate(range(min = 1, max = 100))]
+    page_size: u8,
+}
+
+async fn test_handler(settings: OptionalJson<Settings>) -> HttpResponse {
+    match settings.into_inner() {
+        Some(settings) => HttpResponse::Ok().body(settings.page_size.to_string()),
+        None => HttpResponse::Ok().body("default"),
+    }
+}
+
+#[actix_web::test]
+async fn test_optional_json_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post().uri("/test").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "default");
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(Settings { page_size: 20 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "20");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(Settings { page_size: 200 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("content-type", "application/json"))
+        .set_payload("{\"page_size\": ")
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_optional_query_validation() {
+    let app = test::init_service(App::new().service(web::resource("/test").to(
+        |settings: OptionalQuery<Settings>| async move {
+            HttpResponse::Ok().body(format!("{:?}", settings.into_inner().map(|s| s.page_size)))
+        },
+    )))
+    .await;
+
+    let req = test::TestRequest::with_uri("/test").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "None");
+
+    let req = test::TestRequest::with_uri("/test?page_size=5").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Some(5)");
+
+    let req = test::TestRequest::with_uri("/test?page_size=0").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_optional_form_validation_error() {
+    let form_config = FormConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(App::new().app_data(form_config).service(
+        web::resource("/test").route(web::post().to(
+            |settings: OptionalForm<Settings>| async move {
+                assert!(settings.is_none());
+                HttpResponse::Ok().finish()
+            },
+        )),
+    ))
+    .await;
+
+    let req = test::TestRequest::post().uri("/test").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_form(Settings { page_size: 0 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
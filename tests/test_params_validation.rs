@@ -0,0 +1,132 @@
+use actix_web::{
+    error, http::header::CONTENT_TYPE, http::StatusCode, test, test::call_service, web, App,
+    HttpResponse,
+};
+use actix_web_validator::{Error, Params, ParamsConfig};
+use serde::Deserialize;
+use serde_json::json;
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Validate, Deserialize)]
+#[validate(schema(function = "validate_ids"))]
+struct UpdateParams {
+    #[serde(rename = "path.id")]
+    path_id: u64,
+    #[serde(rename = "query.dry_run", default)]
+    dry_run: bool,
+    #[serde(rename = "body.id")]
+    id: u64,
+    #[serde(rename = "body.name")]
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+fn validate_ids(params: &UpdateParams) -> Result<(), ValidationError> {
+    if params.path_id != params.id {
+        return Err(ValidationError::new("id_mismatch"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct SearchParams {
+    #[serde(rename = "path.folder")]
+    folder: String,
+    #[serde(rename = "query.page")]
+    #[validate(range(min = 1))]
+    page: u32,
+}
+
+async fn test_handler(params: Params<UpdateParams>) -> HttpResponse {
+    HttpResponse::Ok().body(format!("{}:{}", params.name, params.dry_run))
+}
+
+#[actix_web::test]
+async fn test_params_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test/{id}").route(web::put().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::put()
+        .uri("/test/42?dry_run=true")
+        .set_json(json!({"id": 42, "name": "john"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "john:true");
+
+    // Test 400 status
+    let req = test::TestRequest::put()
+        .uri("/test/42")
+        .set_json(json!({"id": 24, "name": "john"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::put()
+        .uri("/test/42")
+        .set_json(json!({"id": 42, "name": "jo"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::put()
+        .uri("/test/abc")
+        .set_json(json!({"id": 42, "name": "john"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::put()
+        .uri("/test/42")
+        .insert_header((CONTENT_TYPE, "text/plain"))
+        .set_payload(r#"{"id": 42, "name": "john"}"#)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_params_without_body() {
+    let app = test::init_service(App::new().service(web::resource("/test/{folder}").to(
+        |params: Params<SearchParams>| async move {
+            assert_eq!(params.folder, "inbox");
+            assert_eq!(params.page, 2);
+            HttpResponse::Ok().finish()
+        },
+    )))
+    .await;
+
+    let req = test::TestRequest::with_uri("/test/inbox?page=2").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::with_uri("/test/inbox?page=0").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_params_validation_error() {
+    let params_config = ParamsConfig::default().error_handler(|err, _req| {
+        assert!(matches!(err, Error::Validate(_)));
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new().service(
+            web::resource("/test/{id}")
+                .app_data(params_config)
+                .route(web::put().to(test_handler)),
+        ),
+    )
+    .await;
+
+    let req = test::TestRequest::put()
+        .uri("/test/42")
+        .set_json(json!({"id": 24, "name": "john"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
@@ -23,23 +23,23 @@ async fn test_handler(_query: Path<PathParams>) -> HttpResponse {
 
 #[actix_web::test]
 async fn test_path_validation() {
-    let mut app =
+    let app =
         test::init_service(App::new().service(web::resource("/test/{id}/").to(test_handler))).await;
 
     // Test 400 status
     let req = test::TestRequest::with_uri("/test/42/").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::NOT_FOUND);
 
     // Test 200 status
     let req = test::TestRequest::with_uri("/test/28/").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 }
 
 #[actix_web::test]
 async fn test_custom_path_validation_error() {
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new()
             .app_data(
                 actix_web_validator::PathConfig::default().error_handler(|err, _req| {
@@ -52,13 +52,13 @@ async fn test_custom_path_validation_error() {
     .await;
 
     let req = test::TestRequest::with_uri("/test/42/").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::CONFLICT);
 }
 
 #[actix_web::test]
 async fn test_deref_validated_path() {
-    let mut app = test::init_service(App::new().service(web::resource("/test/{id}/").to(
+    let app = test::init_service(App::new().service(web::resource("/test/{id}/").to(
         |query: Path<PathParams>| async move {
             assert_eq!(query.id, 28);
             HttpResponse::Ok().finish()
@@ -67,7 +67,7 @@ async fn test_deref_validated_path() {
     .await;
 
     let req = test::TestRequest::with_uri("/test/28/").to_request();
-    call_service(&mut app, req).await;
+    call_service(&app, req).await;
 }
 
 #[actix_web::test]
@@ -81,9 +81,9 @@ async fn test_path_implementation() {
         HttpResponse::Ok().finish()
     }
 
-    let mut app =
+    let app =
         test::init_service(App::new().service(web::resource("/test/{id}/").to(test_handler))).await;
     let req = test::TestRequest::with_uri("/test/28/").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 }
@@ -0,0 +1,96 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{QsForm, QsFormConfig};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Address {
+    #[validate(length(min = 1))]
+    city: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct User {
+    #[validate(length(min = 3))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+async fn test_handler(user: QsForm<User>) -> HttpResponse {
+    let user = user.into_inner();
+    HttpResponse::Ok().body(format!("{} {}", user.name, user.address.city))
+}
+
+fn form(body: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("content-type", "application/x-www-form-urlencoded"))
+        .set_payload(body.to_owned())
+}
+
+#[actix_web::test]
+async fn test_qsform_validation() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = form("name=John&address[city]=Moscow").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "John Moscow");
+
+    let req = form("name=John&address%5Bcity%5D=Moscow").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Test 400 status
+    let req = form("name=John&address[city]=").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = form("name=Jo&address[city]=Moscow").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("content-type", "application/json"))
+        .set_payload("name=John&address[city]=Moscow")
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_qsform_validation_error() {
+    let qs_form_config = QsFormConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new()
+            .app_data(qs_form_config)
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = form("name=John&address[city]=").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
+
+#[actix_web::test]
+async fn test_qsform_limit() {
+    let app = test::init_service(
+        App::new()
+            .app_data(QsFormConfig::default().limit(8))
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = form("name=John&address[city]=Moscow").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
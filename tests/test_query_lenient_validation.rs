@@ -0,0 +1,122 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{QsQuery, QsQueryConfig, Query, QueryConfig};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct PageParams {
+    #[validate(range(min = 1, max = 100))]
+    per_page: u32,
+    page: Option<u32>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct SearchParams {
+    #[validate(length(min = 1))]
+    q: String,
+    #[validate(nested)]
+    #[serde(flatten)]
+    page: PageParams,
+    #[serde(flatten)]
+    filter: Filter,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum Filter {
+    Price { max: f64 },
+    Stock { available: bool },
+}
+
+fn render(search: SearchParams) -> HttpResponse {
+    let filter = match search.filter {
+        Filter::Price { max } => format!("price<={max}"),
+        Filter::Stock { available } => format!("available={available}"),
+    };
+    HttpResponse::Ok().body(format!(
+        "{} {} {:?} {filter}",
+        search.q, search.page.per_page, search.page.page
+    ))
+}
+
+#[actix_web::test]
+async fn test_query_lenient_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(QueryConfig::default().lenient(true))
+            .service(
+                web::resource("/test")
+                    .to(|search: Query<SearchParams>| async { render(search.into_inner()) }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req =
+        test::TestRequest::with_uri("/test?q=book&per_page=20&kind=price&max=9.5").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "book 20 None price<=9.5");
+
+    let req =
+        test::TestRequest::with_uri("/test?q=book&per_page=5&page=2&kind=stock&available=true")
+            .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "book 5 Some(2) available=true");
+
+    // Test 400 status
+    let req =
+        test::TestRequest::with_uri("/test?q=book&per_page=500&kind=price&max=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/test?q=book&per_page=x&kind=price&max=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_query_lenient_disabled_by_default() {
+    let app = test::init_service(
+        App::new().service(
+            web::resource("/test")
+                .to(|search: Query<SearchParams>| async { render(search.into_inner()) }),
+        ),
+    )
+    .await;
+
+    let req =
+        test::TestRequest::with_uri("/test?q=book&per_page=20&kind=price&max=9.5").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_qsquery_lenient_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(QsQueryConfig::default().lenient(true))
+            .service(
+                web::resource("/test")
+                    .to(|search: QsQuery<SearchParams>| async { render(search.into_inner()) }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req =
+        test::TestRequest::with_uri("/test?q=book&per_page=20&page=3&kind=stock&available=false")
+            .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        "book 20 Some(3) available=false"
+    );
+
+    // Test 400 status
+    let req = test::TestRequest::with_uri("/test?q=&per_page=20&kind=price&max=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,89 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Query, QueryConfig};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Tag(String);
+
+impl Validate for Tag {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if !self.0.chars().all(char::is_alphanumeric) {
+            errors.add("tag", ValidationError::new("alphanumeric"));
+            return Err(errors);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Search {
+    #[validate(length(max = 3), nested)]
+    #[serde(default)]
+    tag: Vec<Tag>,
+    #[validate(length(min = 1))]
+    #[serde(default)]
+    ids: Vec<u64>,
+    #[validate(range(max = 100))]
+    page: Option<u32>,
+}
+
+async fn test_handler(search: Query<Search>) -> HttpResponse {
+    let search = search.into_inner();
+    let tags: Vec<_> = search.tag.into_iter().map(|tag| tag.0).collect();
+    HttpResponse::Ok().body(format!("{tags:?} {:?} {:?}", search.ids, search.page))
+}
+
+#[actix_web::test]
+async fn test_query_multi_value_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(QueryConfig::default().comma_separated(["ids"]))
+            .service(web::resource("/test").to(test_handler)),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::with_uri("/test?tag=a&tag=b&ids=1,2&ids=3&page=2").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        r#"["a", "b"] [1, 2, 3] Some(2)"#
+    );
+
+    let req = test::TestRequest::with_uri("/test?tag=a&ids=7").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, r#"["a"] [7] None"#);
+
+    // Test 400 status
+    let req = test::TestRequest::with_uri("/test?tag=a&tag=b%2Bc&ids=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("tag[1].tag"));
+
+    let req = test::TestRequest::with_uri("/test?ids=1,x").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/test?ids=1&page=1&page=2").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/test?tag=a&tag=b&tag=c&tag=d&ids=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_query_multi_value_disabled_by_default() {
+    let app = test::init_service(App::new().service(web::resource("/test").to(test_handler))).await;
+
+    let req = test::TestRequest::with_uri("/test?tag=a&tag=b&ids=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
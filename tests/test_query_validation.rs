@@ -15,23 +15,22 @@ async fn test_handler(_query: Query<QueryParams>) -> HttpResponse {
 
 #[actix_web::test]
 async fn test_query_validation() {
-    let mut app =
-        test::init_service(App::new().service(web::resource("/test").to(test_handler))).await;
+    let app = test::init_service(App::new().service(web::resource("/test").to(test_handler))).await;
 
     // Test 400 status
     let req = test::TestRequest::with_uri("/test?id=42").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 
     // Test 200 status
     let req = test::TestRequest::with_uri("/test?id=28").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 }
 
 #[actix_web::test]
 async fn test_custom_query_validation_error() {
-    let mut app = test::init_service(
+    let app = test::init_service(
         App::new()
             .app_data(
                 actix_web_validator::QueryConfig::default().error_handler(|err, _req| {
@@ -45,13 +44,13 @@ async fn test_custom_query_validation_error() {
     .await;
 
     let req = test::TestRequest::with_uri("/test?id=42").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::CONFLICT);
 }
 
 #[actix_web::test]
 async fn test_deref_validated_query() {
-    let mut app = test::init_service(App::new().service(web::resource("/test").to(
+    let app = test::init_service(App::new().service(web::resource("/test").to(
         |query: Query<QueryParams>| async move {
             assert_eq!(query.id, 28);
             HttpResponse::Ok().finish()
@@ -60,7 +59,7 @@ async fn test_deref_validated_query() {
     .await;
 
     let req = test::TestRequest::with_uri("/test?id=28").to_request();
-    call_service(&mut app, req).await;
+    call_service(&app, req).await;
 }
 
 #[actix_web::test]
@@ -72,9 +71,8 @@ async fn test_query_implementation() {
         HttpResponse::Ok().finish()
     }
 
-    let mut app =
-        test::init_service(App::new().service(web::resource("/test").to(test_handler))).await;
+    let app = test::init_service(App::new().service(web::resource("/test").to(test_handler))).await;
     let req = test::TestRequest::with_uri("/test?id=28").to_request();
-    let resp = call_service(&mut app, req).await;
+    let resp = call_service(&app, req).await;
     assert_eq!(resp.status(), StatusCode::OK);
 }
@@ -0,0 +1,113 @@
+use actix_web::{
+    dev::Service, error, http::StatusCode, test, test::call_service, web, App, HttpMessage,
+    HttpRequest, HttpResponse,
+};
+use actix_web_validator::{Json, Query, QueryConfig, RequestValidated, ValidateRequest};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Role {
+    Admin,
+    User,
+}
+
+#[derive(Debug, Validate, Serialize, Deserialize)]
+struct Task {
+    #[validate(range(max = 10))]
+    priority: u8,
+}
+
+impl ValidateRequest for Task {
+    fn validate_request(&self, req: &HttpRequest) -> Result<(), ValidationErrors> {
+        let is_admin = req.extensions().get::<Role>() == Some(&Role::Admin);
+        if self.priority > 5 && !is_admin {
+            let mut errors = ValidationErrors::new();
+            errors.add("priority", ValidationError::new("forbidden"));
+            return Err(errors);
+        }
+        Ok(())
+    }
+}
+
+async fn test_handler(task: RequestValidated<Json<Task>>) -> HttpResponse {
+    HttpResponse::Ok().body(task.into_inner().priority.to_string())
+}
+
+#[actix_web::test]
+async fn test_request_validation() {
+    let app = test::init_service(
+        App::new()
+            .wrap_fn(|req, srv| {
+                let role = match req.headers().contains_key("x-admin") {
+                    true => Role::Admin,
+                    false => Role::User,
+                };
+                req.extensions_mut().insert(role);
+                srv.call(req)
+            })
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(Task { priority: 3 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("x-admin", "1"))
+        .set_json(Task { priority: 8 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "8");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(Task { priority: 8 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("x-admin", "1"))
+        .set_json(Task { priority: 11 })
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn test_custom_request_validation_error() {
+    let query_config = QueryConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new()
+            .app_data(query_config)
+            .wrap_fn(|req, srv| {
+                req.extensions_mut().insert(Role::User);
+                srv.call(req)
+            })
+            .service(
+                web::resource("/test")
+                    .to(|_: RequestValidated<Query<Task>>| async { HttpResponse::Ok().finish() }),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::with_uri("/test?priority=3").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::with_uri("/test?priority=8").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
@@ -0,0 +1,193 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{
+    BoundedU64, EmailAddress, Error, Form, FormConfig, Json, JsonConfig, NonEmptyString, Path,
+    PathConfig, QsQuery, QsQueryConfig, Slug, Uuid,
+};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct User {
+    name: NonEmptyString<16>,
+    email: EmailAddress,
+    age: BoundedU64<18, 130>,
+    profile: Option<Slug>,
+    #[serde(default)]
+    tags: Vec<Slug>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Lookup {
+    id: Uuid,
+}
+
+/// Respond with codes of validation errors, or `deserialize` for other errors.
+fn error_codes(err: Error) -> actix_web::Error {
+    let body = match &err {
+        Error::Validate(e) => actix_web_validator::error::flatten_errors(e)
+            .iter()
+            .map(|(_, field, err)| format!("{field}: {}", err.code))
+            .collect::<Vec<_>>()
+            .join(", "),
+        _ => "deserialize".to_owned(),
+    };
+    error::InternalError::from_response(err, HttpResponse::BadRequest().body(body)).into()
+}
+
+async fn user_handler(user: Json<User>) -> HttpResponse {
+    let profile = user
+        .profile
+        .as_ref()
+        .map_or("-", |profile| profile.as_str());
+    HttpResponse::Ok().body(format!(
+        "{} {} {} {profile} {}",
+        user.name,
+        user.email,
+        *user.age,
+        user.tags.len()
+    ))
+}
+
+#[actix_web::test]
+async fn test_scalar_json_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().error_handler(|err, _| error_codes(err)))
+            .service(web::resource("/test").route(web::post().to(user_handler))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/test")
+        .set_json(serde_json::json!({
+            "name": "John",
+            "email": "john@example.com",
+            "age": 42,
+            "profile": "john-doe",
+            "tags": ["a", "b-2"],
+        }))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        "John john@example.com 42 john-doe 2"
+    );
+
+    // Test 400 status
+    let cases = [
+        (
+            r#"{"name": "", "email": "john@example.com", "age": 42}"#,
+            "name: length",
+        ),
+        (
+            r#"{"name": "John", "email": "john", "age": 42}"#,
+            "email: email",
+        ),
+        (
+            r#"{"name": "John", "email": "john@example.com", "age": 7}"#,
+            "age: range",
+        ),
+        (
+            r#"{"name": "John", "email": "john@example.com", "age": 42, "profile": "John Doe"}"#,
+            "profile: slug",
+        ),
+        (
+            r#"{"name": "John", "email": "john@example.com", "age": 500}"#,
+            "age: range",
+        ),
+        (
+            r#"{"name": "John", "email": "john@example.com", "age": 42, "tags": ["a", "B"]}"#,
+            "tags[1]: slug",
+        ),
+        (
+            r#"{"name": "John", "email": "john@example.com", "age": "42"}"#,
+            "deserialize",
+        ),
+    ];
+    for (body, expected) in cases {
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .insert_header(("content-type", "application/json"))
+            .set_payload(body)
+            .to_request();
+        let resp = call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(test::read_body(resp).await, expected);
+    }
+}
+
+#[actix_web::test]
+async fn test_scalar_form_qsquery_and_path_validation() {
+    let app = test::init_service(
+        App::new()
+            .app_data(FormConfig::default().error_handler(|err, _| error_codes(err)))
+            .app_data(QsQueryConfig::default().error_handler(|err, _| error_codes(err)))
+            .app_data(PathConfig::default().error_handler(|err, _| error_codes(err)))
+            .service(
+                web::resource("/form").route(web::post().to(|user: Form<User>| async move {
+                    HttpResponse::Ok().body(user.email.to_string())
+                })),
+            )
+            .service(
+                web::resource("/lookup").to(|lookup: QsQuery<Lookup>| async move {
+                    HttpResponse::Ok().body(lookup.id.to_string())
+                }),
+            )
+            .service(
+                web::resource("/profiles/{slug}").to(|slug: Path<Slug>| async move {
+                    HttpResponse::Ok().body(slug.into_inner().into_inner())
+                }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([
+            ("name", "John"),
+            ("email", "john@example.com"),
+            ("age", "42"),
+        ])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req =
+        test::TestRequest::with_uri("/lookup?id=67e55044-10b1-426f-9247-bb680e5fe0c8").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        test::read_body(resp).await,
+        "67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+
+    let req = test::TestRequest::with_uri("/profiles/john-doe").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([
+            ("name", "John"),
+            ("email", "john@example.com"),
+            ("age", "200"),
+        ])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, "age: range");
+
+    let req = test::TestRequest::with_uri("/lookup?id=67e55044-10b1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, "id: uuid");
+
+    let req = test::TestRequest::with_uri("/profiles/john--doe").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(test::read_body(resp).await, ": slug");
+}
@@ -0,0 +1,415 @@
+use actix_web::{
+    error, http::StatusCode, middleware, test, test::call_service, web, App, HttpResponse,
+};
+use actix_web_validator::{
+    unknown_fields_header, Batch, Error, Form, FormConfig, Json, JsonConfig, JsonWithRaw,
+    MergePatch, Params, ParamsConfig, QsQuery, QsQueryConfig, Query, QueryConfig, UnknownFields,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Item {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Order {
+    #[validate(range(min = 1, max = 100))]
+    page_size: Option<u8>,
+    #[validate(nested)]
+    #[serde(default)]
+    items: Vec<Item>,
+}
+
+async fn test_handler(order: Json<Order>) -> HttpResponse {
+    HttpResponse::Ok().body(format!("{:?}", order.page_size))
+}
+
+fn order(body: &str) -> test::TestRequest {
+    test::TestRequest::post()
+        .uri("/test")
+        .insert_header(("content-type", "application/json"))
+        .set_payload(body.to_owned())
+}
+
+#[actix_web::test]
+async fn test_unknown_fields_ignored_by_default() {
+    let app = test::init_service(
+        App::new().service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = order(r#"{"pageszie": 10}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "None");
+}
+
+#[actix_web::test]
+async fn test_json_unknown_fields_reject() {
+    let json_config = JsonConfig::default()
+        .unknown_fields(UnknownFields::Reject)
+        .error_handler(|err, _req| {
+            let body = match &err {
+                Error::Validate(e) => e.to_string(),
+                _ => String::new(),
+            };
+            error::InternalError::from_response(err, HttpResponse::Conflict().body(body)).into()
+        });
+    let app = test::init_service(
+        App::new()
+            .app_data(json_config)
+            .service(web::resource("/test").route(web::post().to(test_handler))),
+    )
+    .await;
+
+    let req = order(r#"{"page_size": 10, "items": [{"name": "book"}]}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = order(r#"{"pageszie": 10}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("pageszie"));
+    assert!(body.contains("unknown_field"));
+
+    let req =
+        order(r#"{"items": [{"name": "book"}, {"name": "pen", "nmae": "pen"}]}"#).to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("items[1].nmae"));
+}
+
+#[actix_web::test]
+async fn test_query_unknown_fields_warn() {
+    let app = test::init_service(
+        App::new()
+            .wrap(middleware::from_fn(unknown_fields_header))
+            .app_data(QueryConfig::default().unknown_fields(UnknownFields::Warn))
+            .service(web::resource("/test").to(|order: Query<Order>| async move {
+                HttpResponse::Ok().body(format!("{:?}", order.page_size))
+            })),
+    )
+    .await;
+
+    let req = test::TestRequest::with_uri("/test?page_size=5").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert!(!resp.headers().contains_key("x-unknown-fields"));
+
+    let req = test::TestRequest::with_uri("/test?page_size=5&pageszie=6&sort=asc").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get("x-unknown-fields").unwrap(),
+        "pageszie, sort"
+    );
+    assert_eq!(test::read_body(resp).await, "Some(5)");
+}
+
+#[actix_web::test]
+async fn test_form_and_qsquery_unknown_fields_reject() {
+    let app = test::init_service(
+        App::new()
+            .app_data(FormConfig::default().unknown_fields(UnknownFields::Reject))
+            .app_data(QsQueryConfig::default().unknown_fields(UnknownFields::Reject))
+            .service(
+                web::resource("/form")
+                    .route(web::post().to(|_: Form<Order>| async { HttpResponse::Ok().finish() })),
+            )
+            .service(
+                web::resource("/qs").to(|_: QsQuery<Order>| async { HttpResponse::Ok().finish() }),
+            ),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([("page_size", "5")])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .uri("/form")
+        .set_form([("pageszie", "5")])
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/qs?items[0][name]=book").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::with_uri("/qs?items[0][name]=book&items[0][price]=1").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("items[0].price"));
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Search {
+    #[validate(length(min = 1))]
+    q: String,
+    #[serde(flatten)]
+    #[validate(nested)]
+    page: Page,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Page {
+    #[validate(range(min = 1, max = 100))]
+    page_size: Option<u8>,
+}
+
+#[actix_web::test]
+async fn test_flatten_unknown_fields_reject() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().unknown_fields(UnknownFields::Reject))
+            .app_data(
+                QueryConfig::default()
+                    .lenient(true)
+                    .unknown_fields(UnknownFields::Reject),
+            )
+            .service(web::resource("/json").route(web::post().to(
+                |search: Json<Search>| async move {
+                    HttpResponse::Ok().body(format!("{:?}", search.page.page_size))
+                },
+            )))
+            .service(
+                web::resource("/query").to(|search: Query<Search>| async move {
+                    HttpResponse::Ok().body(format!("{:?}", search.page.page_size))
+                }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({"q": "a", "page_size": 3}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Some(3)");
+
+    let req = test::TestRequest::with_uri("/query?q=a&page_size=3").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "Some(3)");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({"q": "a", "pageszie": 3}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("pageszie: Validation error: unknown_field"));
+    assert!(!body.contains("q:"));
+
+    let req = test::TestRequest::with_uri("/query?q=a&page_size=3&pageszie=3").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("pageszie: Validation error: unknown_field"));
+    assert!(!body.contains("page_size:"));
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Labeled {
+    #[validate(length(min = 1))]
+    name: String,
+    #[serde(flatten)]
+    labels: HashMap<String, String>,
+}
+
+#[actix_web::test]
+async fn test_flatten_large_payload_reject() {
+    let app = test::init_service(
+        App::new()
+            .app_data(
+                JsonConfig::default()
+                    .limit(1 << 20)
+                    .unknown_fields(UnknownFields::Reject),
+            )
+            .service(web::resource("/labels").route(web::post().to(
+                |labeled: Json<Labeled>| async move {
+                    HttpResponse::Ok().body(labeled.labels.len().to_string())
+                },
+            )))
+            .service(web::resource("/search").route(web::post().to(
+                |search: Json<Search>| async move {
+                    HttpResponse::Ok().body(format!("{:?}", search.page.page_size))
+                },
+            ))),
+    )
+    .await;
+
+    // Test 200 status
+    let mut body: serde_json::Map<_, _> = (0..10)
+        .map(|i| (format!("label{i}"), serde_json::json!("x")))
+        .collect();
+    body.insert("name".into(), serde_json::json!("a"));
+    let req = test::TestRequest::post()
+        .uri("/labels")
+        .set_json(&body)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "10");
+
+    // Test 400 status
+    let mut body: serde_json::Map<_, _> = (0..3000)
+        .map(|i| (format!("label{i}"), serde_json::json!("x")))
+        .collect();
+    body.insert("name".into(), serde_json::json!("a"));
+    let req = test::TestRequest::post()
+        .uri("/labels")
+        .set_json(&body)
+        .to_request();
+    let started = Instant::now();
+    let resp = call_service(&app, req).await;
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains(": Validation error: unknown_field"));
+    assert!(!body.contains("name:"));
+
+    let mut body: serde_json::Map<_, _> = (0..3000)
+        .map(|i| (format!("field{i}"), serde_json::json!(1)))
+        .collect();
+    body.insert("q".into(), serde_json::json!("a"));
+    body.insert("page_size".into(), serde_json::json!(3));
+    let req = test::TestRequest::post()
+        .uri("/search")
+        .set_json(&body)
+        .to_request();
+    let started = Instant::now();
+    let resp = call_service(&app, req).await;
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("field0: Validation error: unknown_field"));
+    assert!(body.contains("field2999: Validation error: unknown_field"));
+    assert!(!body.contains("page_size:"));
+}
+
+#[derive(Debug, Default, Validate, Serialize, Deserialize)]
+struct Profile {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct ProfileParams {
+    #[serde(rename = "path.id")]
+    #[validate(range(min = 1))]
+    id: u64,
+    #[serde(rename = "body.name")]
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[actix_web::test]
+async fn test_unknown_fields_of_other_extractors() {
+    let app = test::init_service(
+        App::new()
+            .app_data(JsonConfig::default().unknown_fields(UnknownFields::Reject))
+            .app_data(ParamsConfig::default().unknown_fields(UnknownFields::Reject))
+            .service(web::resource("/batch").route(
+                web::post().to(|batch: Batch<Profile>| async move {
+                    HttpResponse::Ok().body(batch.len().to_string())
+                }),
+            ))
+            .service(web::resource("/patch").route(
+                web::patch().to(|_: MergePatch<Profile>| async { HttpResponse::Ok().finish() }),
+            ))
+            .service(web::resource("/raw").route(
+                web::post().to(|_: JsonWithRaw<Profile>| async { HttpResponse::Ok().finish() }),
+            ))
+            .service(web::resource("/params/{id}/{tab}").route(web::post().to(
+                |params: Params<ProfileParams>| async move {
+                    HttpResponse::Ok().body(format!("{} {}", params.id, params.name))
+                },
+            ))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/batch")
+        .set_json(serde_json::json!([{"name": "Alice"}, {"name": "Bob"}]))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "2");
+
+    // Unused path parameters come from the route, not from the client
+    let req = test::TestRequest::post()
+        .uri("/params/1/settings")
+        .set_json(serde_json::json!({"name": "Alice"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "1 Alice");
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/batch")
+        .set_json(serde_json::json!([{"name": "Alice"}, {"name": "Bob", "nmae": "Bob"}]))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body).unwrap().contains("[1].nmae"));
+
+    let req = test::TestRequest::patch()
+        .uri("/patch")
+        .insert_header(("content-type", "application/merge-patch+json"))
+        .set_payload(r#"{"nmae": "Alice"}"#)
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    assert!(std::str::from_utf8(&body)
+        .unwrap()
+        .contains("nmae: Validation error: unknown_field"));
+
+    let req = test::TestRequest::post()
+        .uri("/raw")
+        .set_json(serde_json::json!({"name": "Alice", "nmae": "Alice"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::post()
+        .uri("/params/1/settings?sort=asc")
+        .set_json(serde_json::json!({"name": "Alice", "nmae": "Alice"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = test::read_body(resp).await;
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains("body.nmae"));
+    assert!(body.contains("query.sort"));
+    assert!(!body.contains("path.tab"));
+}
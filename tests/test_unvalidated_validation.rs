@@ -0,0 +1,93 @@
+use actix_web::{error, http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Json, JsonConfig, Unvalidated};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+struct Balance(u64);
+
+fn validate_balance(amount: u64, balance: &Balance) -> Result<(), ValidationError> {
+    if amount > balance.0 {
+        return Err(ValidationError::new("insufficient_balance"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[validate(context = Balance)]
+struct Withdrawal {
+    #[validate(custom(function = "validate_balance", use_context))]
+    amount: u64,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Rename {
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+async fn withdraw(withdrawal: Unvalidated<Json<Withdrawal>>) -> Result<HttpResponse, error::Error> {
+    // the balance would be loaded from the database
+    let balance = Balance(100);
+    let withdrawal = withdrawal.validate_with(&balance)?;
+    Ok(HttpResponse::Ok().body(format!("{}", balance.0 - withdrawal.amount)))
+}
+
+async fn rename(mut rename: Unvalidated<Json<Rename>>) -> Result<HttpResponse, error::Error> {
+    rename.name = rename.name.trim().to_owned();
+    let rename = rename.validate()?;
+    Ok(HttpResponse::Ok().body(rename.name))
+}
+
+#[actix_web::test]
+async fn test_unvalidated_validation() {
+    let json_config = JsonConfig::default().error_handler(|err, _req| {
+        error::InternalError::from_response(err, HttpResponse::Conflict().finish()).into()
+    });
+    let app = test::init_service(
+        App::new()
+            .app_data(json_config)
+            .service(web::resource("/withdraw").route(web::post().to(withdraw)))
+            .service(web::resource("/rename").route(web::post().to(rename))),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/withdraw")
+        .set_json(serde_json::json!({"amount": 30}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "70");
+
+    let req = test::TestRequest::post()
+        .uri("/rename")
+        .set_json(serde_json::json!({"name": " John "}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "John");
+
+    // Test custom error handler for deferred validation
+    let req = test::TestRequest::post()
+        .uri("/withdraw")
+        .set_json(serde_json::json!({"amount": 300}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    let req = test::TestRequest::post()
+        .uri("/rename")
+        .set_json(serde_json::json!({"name": "  "}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    // and for deserialization
+    let req = test::TestRequest::post()
+        .uri("/rename")
+        .set_json(serde_json::json!({"title": "John"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+}
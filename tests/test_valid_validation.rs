@@ -0,0 +1,142 @@
+use std::ops::Deref;
+
+use actix_web::{
+    dev::Payload, error, http::StatusCode, test, test::call_service, web, App, FromRequest,
+    HttpRequest, HttpResponse,
+};
+use actix_web_validator::{Error, Valid, ValidConfig};
+use futures::future::{ready, Ready};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Search {
+    #[validate(length(min = 2))]
+    q: String,
+}
+
+#[derive(Debug, Validate)]
+struct ApiKey {
+    #[validate(length(equal = 8))]
+    key: String,
+}
+
+/// Extractor reading `ApiKey` from a header, failing with 401 without it.
+struct ApiKeyHeader(ApiKey);
+
+impl Deref for ApiKeyHeader {
+    type Target = ApiKey;
+
+    fn deref(&self) -> &ApiKey {
+        &self.0
+    }
+}
+
+impl FromRequest for ApiKeyHeader {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let key = req
+            .headers()
+            .get("x-api-key")
+            .and_then(|key| key.to_str().ok())
+            .map(|key| {
+                ApiKeyHeader(ApiKey {
+                    key: key.to_owned(),
+                })
+            })
+            .ok_or_else(|| error::ErrorUnauthorized("missing api key"));
+        ready(key)
+    }
+}
+
+#[actix_web::test]
+async fn test_valid_validation() {
+    let app = test::init_service(
+        App::new()
+            .service(web::resource("/json").route(web::post().to(
+                |search: Valid<web::Json<Search>>| async move {
+                    HttpResponse::Ok().body(search.into_inner().into_inner().q)
+                },
+            )))
+            .service(
+                web::resource("/query").to(|search: Valid<web::Query<Search>>| async move {
+                    HttpResponse::Ok().body(search.q.clone())
+                }),
+            )
+            .service(
+                web::resource("/key").to(|api_key: Valid<ApiKeyHeader>| async move {
+                    HttpResponse::Ok().body(api_key.key.clone())
+                }),
+            ),
+    )
+    .await;
+
+    // Test 200 status
+    let req = test::TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({"q": "rust"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "rust");
+
+    let req = test::TestRequest::with_uri("/query?q=actix").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::with_uri("/key")
+        .insert_header(("x-api-key", "12345678"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Test 400 status
+    let req = test::TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({"q": "r"}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/query?q=a").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = test::TestRequest::with_uri("/key")
+        .insert_header(("x-api-key", "123"))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // Inner extractor errors keep their response
+    let req = test::TestRequest::with_uri("/key").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_custom_valid_validation_error() {
+    let valid_config = ValidConfig::default().error_handler(|err, _req| {
+        let status = match err {
+            Error::Validate(_) => StatusCode::CONFLICT,
+            _ => StatusCode::IM_A_TEAPOT,
+        };
+        error::InternalError::from_response(err, HttpResponse::build(status).finish()).into()
+    });
+    let app = test::init_service(App::new().app_data(valid_config).service(
+        web::resource("/query").to(|search: Valid<web::Query<Search>>| async move {
+            HttpResponse::Ok().body(search.q.clone())
+        }),
+    ))
+    .await;
+
+    let req = test::TestRequest::with_uri("/query?q=a").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    let req = test::TestRequest::with_uri("/query").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::IM_A_TEAPOT);
+}
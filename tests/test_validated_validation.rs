@@ -0,0 +1,65 @@
+use actix_web::{http::StatusCode, test, test::call_service, web, App, HttpResponse};
+use actix_web_validator::{Json, Query, Validated};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Transfer {
+    #[validate(range(min = 1, max = 1000))]
+    amount: u32,
+}
+
+fn book_transfer(transfer: Validated<Transfer>) -> String {
+    format!("booked {}", transfer.amount)
+}
+
+async fn json_handler(transfer: Json<Transfer>) -> actix_web::Result<HttpResponse> {
+    Ok(HttpResponse::Ok().body(book_transfer(transfer.try_into()?)))
+}
+
+async fn query_handler(transfer: Query<Transfer>) -> actix_web::Result<HttpResponse> {
+    Ok(HttpResponse::Ok().body(book_transfer(transfer.try_into()?)))
+}
+
+#[actix_web::test]
+async fn test_validated_from_extractors() {
+    let app = test::init_service(
+        App::new()
+            .service(web::resource("/json").route(web::post().to(json_handler)))
+            .service(web::resource("/query").to(query_handler)),
+    )
+    .await;
+
+    let req = test::TestRequest::post()
+        .uri("/json")
+        .set_json(serde_json::json!({"amount": 10}))
+        .to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "booked 10");
+
+    let req = test::TestRequest::with_uri("/query?amount=20").to_request();
+    let resp = call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(test::read_body(resp).await, "booked 20");
+}
+
+#[actix_web::test]
+async fn test_validated_outside_extractors() {
+    let transfer = Validated::new(Transfer { amount: 5 }).unwrap();
+    assert_eq!(book_transfer(transfer), "booked 5");
+
+    let errors = Validated::new(Transfer { amount: 0 }).unwrap_err();
+    assert!(errors.field_errors().contains_key("amount"));
+
+    // Fields of the extractors are public, so conversion validates again
+    let mut json = Json(Transfer { amount: 5 });
+    json.0.amount = 0;
+    assert!(Validated::try_from(json).is_err());
+
+    let transfer: Validated<Transfer> = serde_json::from_str(r#"{"amount": 7}"#).unwrap();
+    assert_eq!(transfer.into_inner().amount, 7);
+
+    let error = serde_json::from_str::<Validated<Transfer>>(r#"{"amount": 5000}"#).unwrap_err();
+    assert!(error.to_string().contains("amount"));
+}